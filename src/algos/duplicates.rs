@@ -4,9 +4,13 @@ use std::collections::hash_map::Entry;
 use std::hash::Hash;
 
 /// mark duplicates to drop in each columns
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Duplicates {
+    /// keep the first occurrence
     First,
+    /// keep the last occurrence
     Last,
+    /// mark all occurrences as duplicated
     None,
 }
 
@@ -0,0 +1,169 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Scalar};
+
+use super::hash::HashableScalar;
+use super::join::USIZE_MISSING;
+use traits::Slicer;
+
+/// Encode values to integer codes
+pub struct Factorize;
+
+impl Factorize {
+    /// Return codes and locations where each unique value first appears.
+    /// Codes are assigned in appearance order, use sort_codes to re-assign
+    /// them in sorted order of unique values
+    pub fn factorize<T>(values: &[T]) -> (Vec<usize>, Vec<usize>)
+    where
+        T: Clone + Hash + Eq,
+    {
+        Factorize::factorize_by(values, |_| false)
+    }
+
+    /// Factorize values which can be converted to Scalar, thus floats are also
+    /// supported. Values flagged in nulls (and NaN) are encoded as USIZE_MISSING
+    pub fn factorize_scalars<T>(
+        values: &[T],
+        nulls: &[bool],
+        sort: bool,
+    ) -> (Vec<usize>, Vec<usize>)
+    where
+        T: Clone,
+        Scalar: From<T>,
+    {
+        assert!(values.len() == nulls.len(), "Length mismatch!");
+        let keys: Vec<HashableScalar> = values
+            .iter()
+            .zip(nulls.iter())
+            .map(|(v, &n)| if n {
+                HashableScalar::Null
+            } else {
+                HashableScalar::new(&Scalar::from(v.clone()))
+            })
+            .collect();
+        Factorize::factorize_hashable(&keys, sort)
+    }
+
+    /// Factorize Array, Null is encoded as USIZE_MISSING and is not included in uniques
+    pub fn factorize_array(values: &Array, sort: bool) -> (Vec<usize>, Array) {
+        let keys = HashableScalar::from_array(values);
+        let (codes, locations) = Factorize::factorize_hashable(&keys, sort);
+        // uniques never contain Null, ilocs_forced drops the null mask
+        (codes, values.ilocs_forced(&locations))
+    }
+
+    fn factorize_hashable(keys: &[HashableScalar], sort: bool) -> (Vec<usize>, Vec<usize>) {
+        let (codes, locations) = Factorize::factorize_by(keys, |x| *x == HashableScalar::Null);
+        if sort {
+            Factorize::sort_codes(keys, codes, locations)
+        } else {
+            (codes, locations)
+        }
+    }
+
+    /// Null is encoded as USIZE_MISSING
+    fn factorize_by<T, F>(values: &[T], is_null: F) -> (Vec<usize>, Vec<usize>)
+    where
+        T: Clone + Hash + Eq,
+        F: Fn(&T) -> bool,
+    {
+        let mut codes: Vec<usize> = Vec::with_capacity(values.len());
+        let mut locations: Vec<usize> = vec![];
+        let mut map: HashMap<Cow<T>, usize> = HashMap::new();
+
+        for (i, v) in values.iter().enumerate() {
+            if is_null(v) {
+                codes.push(USIZE_MISSING);
+                continue;
+            }
+            match map.entry(Cow::Borrowed(v)) {
+                Entry::Occupied(e) => codes.push(*e.get()),
+                Entry::Vacant(e) => {
+                    let code = locations.len();
+                    e.insert(code);
+                    locations.push(i);
+                    codes.push(code);
+                }
+            }
+        }
+        (codes, locations)
+    }
+
+    /// Re-assign codes based on sorted order of unique values
+    pub fn sort_codes<T>(
+        values: &[T],
+        codes: Vec<usize>,
+        locations: Vec<usize>,
+    ) -> (Vec<usize>, Vec<usize>)
+    where
+        T: Ord,
+    {
+        let mut order: Vec<usize> = (0..locations.len()).collect();
+        order.sort_by(|&l, &r| values[locations[l]].cmp(&values[locations[r]]));
+
+        // mapping from current code to new code
+        let mut mapper: Vec<usize> = vec![0; order.len()];
+        for (new, &current) in order.iter().enumerate() {
+            mapper[current] = new;
+        }
+        let new_codes: Vec<usize> = codes
+            .into_iter()
+            .map(|c| if c == USIZE_MISSING { c } else { mapper[c] })
+            .collect();
+        let new_locations: Vec<usize> = order.iter().map(|&c| locations[c]).collect();
+        (new_codes, new_locations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::{Array, NullVec};
+
+    use super::Factorize;
+    use algos::join::USIZE_MISSING;
+
+    #[test]
+    fn test_factorize() {
+        let values = vec!["b", "a", "b", "c", "a"];
+
+        let (codes, locations) = Factorize::factorize(&values);
+        assert_eq!(codes, vec![0, 1, 0, 2, 1]);
+        assert_eq!(locations, vec![0, 1, 3]);
+
+        let (codes, locations) = Factorize::sort_codes(&values, codes, locations);
+        assert_eq!(codes, vec![1, 0, 1, 2, 0]);
+        assert_eq!(locations, vec![1, 0, 3]);
+    }
+
+    #[test]
+    fn test_factorize_array() {
+        let values = Array::Float64Array(NullVec::with_mask(vec![2.5, 1.5, 0., 2.5],
+                                                            Some(vec![false, false, true, false])));
+
+        let (codes, uniques) = Factorize::factorize_array(&values, false);
+        assert_eq!(codes, vec![0, 1, USIZE_MISSING, 0]);
+        assert_eq!(uniques, Array::new(vec![2.5, 1.5]));
+
+        let (codes, uniques) = Factorize::factorize_array(&values, true);
+        assert_eq!(codes, vec![1, 0, USIZE_MISSING, 1]);
+        assert_eq!(uniques, Array::new(vec![1.5, 2.5]));
+    }
+
+    #[test]
+    fn test_factorize_scalars() {
+        let values = vec![2.5, 1.5, 0., 2.5, 1.5];
+        let nulls = vec![false, false, true, false, false];
+
+        let (codes, locations) = Factorize::factorize_scalars(&values, &nulls, false);
+        assert_eq!(codes, vec![0, 1, USIZE_MISSING, 0, 1]);
+        assert_eq!(locations, vec![0, 1]);
+
+        let (codes, locations) = Factorize::factorize_scalars(&values, &nulls, true);
+        assert_eq!(codes, vec![1, 0, USIZE_MISSING, 1, 0]);
+        assert_eq!(locations, vec![1, 0]);
+    }
+}
@@ -0,0 +1,132 @@
+use nullvec::prelude::NullVec;
+
+use super::join::USIZE_MISSING;
+use super::window::to_nullvec;
+
+/// How to fill null values
+#[derive(Clone, Debug, PartialEq)]
+pub enum FillNA<T> {
+    /// fill with the given value
+    Value(T),
+    /// propagate the last valid value forward
+    Ffill,
+    /// use the next valid value to fill backward
+    Bfill,
+}
+
+/// Compute locations of values to fill nulls with
+pub struct Propagate;
+
+impl Propagate {
+    /// Return locations of the last valid value for each element, valid elements
+    /// refer to themselves. Only limit consecutive nulls are filled if specified.
+    /// Locations which cannot be filled are USIZE_MISSING.
+    pub fn forward(mask: &[bool], limit: Option<usize>) -> Vec<usize> {
+        let mut indexer: Vec<usize> = Vec::with_capacity(mask.len());
+        let mut last: usize = USIZE_MISSING;
+        let mut filled: usize = 0;
+        // no limit is regarded as the maximum
+        let limit = limit.unwrap_or(usize::MAX);
+        for (i, &is_null) in mask.iter().enumerate() {
+            if !is_null {
+                last = i;
+                filled = 0;
+                indexer.push(i);
+            } else if last != USIZE_MISSING && filled < limit {
+                filled += 1;
+                indexer.push(last);
+            } else {
+                indexer.push(USIZE_MISSING);
+            }
+        }
+        indexer
+    }
+
+    /// Return locations of the next valid value for each element, valid elements
+    /// refer to themselves. Only limit consecutive nulls are filled if specified.
+    /// Locations which cannot be filled are USIZE_MISSING.
+    pub fn backward(mask: &[bool], limit: Option<usize>) -> Vec<usize> {
+        let reversed: Vec<bool> = mask.iter().rev().cloned().collect();
+        let len = mask.len();
+        Propagate::forward(&reversed, limit)
+            .into_iter()
+            .rev()
+            .map(|x| if x == USIZE_MISSING { x } else { len - 1 - x })
+            .collect()
+    }
+}
+
+/// How to interpolate nulls between valid values
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Interpolation {
+    /// linear interpolation regarding values as equally spaced
+    Linear,
+    /// the nearest valid value, the preceding one on ties
+    Nearest,
+}
+
+impl Interpolation {
+    /// Fill nulls between valid values. Values are passed as Option, None is
+    /// regarded as null. Leading and trailing nulls are kept as null.
+    pub fn interpolate(&self, values: &[Option<f64>]) -> NullVec<f64> {
+        let mut new_values: Vec<Option<f64>> = values.to_vec();
+        let mut previous: Option<(usize, f64)> = None;
+        for (j, value) in values.iter().enumerate() {
+            let current = match *value {
+                Some(v) => v,
+                None => continue,
+            };
+            if let Some((i, start)) = previous {
+                for (k, new_value) in new_values.iter_mut().enumerate().take(j).skip(i + 1) {
+                    let filled = match *self {
+                        Interpolation::Linear => {
+                            start + (current - start) * (k - i) as f64 / (j - i) as f64
+                        }
+                        Interpolation::Nearest => if k - i <= j - k { start } else { current },
+                    };
+                    *new_value = Some(filled);
+                }
+            }
+            previous = Some((j, current));
+        }
+        to_nullvec(new_values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::NullVec;
+
+    use super::{Propagate, Interpolation};
+    use algos::join::USIZE_MISSING;
+
+    #[test]
+    fn test_interpolate() {
+        let values = vec![None, Some(1.), None, None, Some(4.), None, Some(5.), None];
+        let mask = Some(vec![true, false, false, false, false, false, false, true]);
+
+        let exp = NullVec::with_mask(vec![0., 1., 2., 3., 4., 4.5, 5., 0.], mask.clone());
+        assert_eq!(Interpolation::Linear.interpolate(&values), exp);
+        let exp = NullVec::with_mask(vec![0., 1., 1., 4., 4., 4., 5., 0.], mask.clone());
+        assert_eq!(Interpolation::Nearest.interpolate(&values), exp);
+    }
+
+    #[test]
+    fn test_forward() {
+        let mask = vec![true, false, true, true, false, true];
+        assert_eq!(Propagate::forward(&mask, None),
+                   vec![USIZE_MISSING, 1, 1, 1, 4, 4]);
+        assert_eq!(Propagate::forward(&mask, Some(1)),
+                   vec![USIZE_MISSING, 1, 1, USIZE_MISSING, 4, 4]);
+    }
+
+    #[test]
+    fn test_backward() {
+        let mask = vec![true, false, true, true, false, true];
+        assert_eq!(Propagate::backward(&mask, None),
+                   vec![1, 1, 4, 4, 4, USIZE_MISSING]);
+        assert_eq!(Propagate::backward(&mask, Some(1)),
+                   vec![1, 1, USIZE_MISSING, 4, 4, USIZE_MISSING]);
+    }
+}
@@ -7,12 +7,22 @@ pub struct HashGrouper<T> {
     pub groups: HashMap<T, Vec<usize>>,
 }
 
+/// Group locations by sorting keys. Locations of each group are stored
+/// as a contiguous slice of indexer.
+pub struct SortGrouper<T> {
+    /// sorted unique keys
+    pub keys: Vec<T>,
+    /// locations sorted by keys, the order is kept within each group
+    pub indexer: Vec<usize>,
+    /// boundaries of each group in indexer, i-th group is indexer[bounds[i]..bounds[i + 1]]
+    pub bounds: Vec<usize>,
+}
+
 pub trait Grouper<T> {
     // ToDo: Implement efficient multimap
-    fn groupby(key: &[T]) -> HashGrouper<T>;
-    fn get(&self, key: &T) -> Option<&Vec<usize>>;
+    fn groupby(key: &[T]) -> Self where Self: Sized;
+    fn get(&self, key: &T) -> Option<&[usize]>;
     fn keys(&self) -> Vec<T>;
-    fn len(&self) -> usize;
 }
 
 impl<T> Grouper<T> for HashGrouper<T>
@@ -30,17 +40,64 @@ where
         HashGrouper { groups: map }
     }
 
-    fn get(&self, key: &T) -> Option<&Vec<usize>> {
-        self.groups.get(key)
+    fn get(&self, key: &T) -> Option<&[usize]> {
+        self.groups.get(key).map(|x| x.as_slice())
     }
 
     fn keys(&self) -> Vec<T> {
         let keys: Vec<T> = self.groups.keys().cloned().collect();
         keys
     }
+}
+
+impl<T> SortGrouper<T>
+where
+    T: Clone + Ord,
+{
+    /// Locations of i-th group
+    pub fn slice(&self, i: usize) -> &[usize] {
+        &self.indexer[self.bounds[i]..self.bounds[i + 1]]
+    }
+}
+
+impl<T> Grouper<T> for SortGrouper<T>
+where
+    T: Clone + Ord,
+{
+    fn groupby(key: &[T]) -> SortGrouper<T> {
+        let mut indexer: Vec<usize> = (0..key.len()).collect();
+        // already sorted keys only need run-length encoding
+        if !key.windows(2).all(|w| w[0] <= w[1]) {
+            // stable sort keeps the original order within each group
+            indexer.sort_by(|&l, &r| key[l].cmp(&key[r]));
+        }
+
+        let mut keys: Vec<T> = vec![];
+        let mut bounds: Vec<usize> = vec![];
+        for (i, &loc) in indexer.iter().enumerate() {
+            if i == 0 || key[loc] != key[indexer[i - 1]] {
+                keys.push(key[loc].clone());
+                bounds.push(i);
+            }
+        }
+        bounds.push(indexer.len());
+
+        SortGrouper {
+            keys,
+            indexer,
+            bounds,
+        }
+    }
 
-    fn len(&self) -> usize {
-        self.groups.len()
+    fn get(&self, key: &T) -> Option<&[usize]> {
+        match self.keys.binary_search(key) {
+            Ok(i) => Some(self.slice(i)),
+            Err(_) => None,
+        }
+    }
+
+    fn keys(&self) -> Vec<T> {
+        self.keys.clone()
     }
 }
 
@@ -49,7 +106,7 @@ where
 mod tests {
 
     use std::collections::HashMap;
-    use super::{Grouper, HashGrouper};
+    use super::{Grouper, HashGrouper, SortGrouper};
 
     #[test]
     fn test_vec_groupby_int() {
@@ -60,10 +117,9 @@ mod tests {
         exp.insert(1, vec![0, 1]);
         exp.insert(2, vec![2, 3]);
         assert_eq!(res.groups, exp);
-        assert_eq!(res.len(), 2);
 
-        assert_eq!(res.get(&1), Some(&vec![0, 1]));
-        assert_eq!(res.get(&2), Some(&vec![2, 3]));
+        assert_eq!(res.get(&1), Some(&[0, 1][..]));
+        assert_eq!(res.get(&2), Some(&[2, 3][..]));
     }
 
     #[test]
@@ -75,10 +131,39 @@ mod tests {
         exp.insert("a", vec![0, 2]);
         exp.insert("b", vec![1, 3]);
         assert_eq!(res.groups, exp);
-        assert_eq!(res.len(), 2);
 
-        assert_eq!(res.get(&"a"), Some(&vec![0, 2]));
-        assert_eq!(res.get(&"b"), Some(&vec![1, 3]));
+        assert_eq!(res.get(&"a"), Some(&[0, 2][..]));
+        assert_eq!(res.get(&"b"), Some(&[1, 3][..]));
+    }
+
+    #[test]
+    fn test_sort_groupby_unsorted() {
+        let key = vec!["b", "a", "b", "c", "a"];
+        let res: SortGrouper<&str> = SortGrouper::groupby(&key);
+
+        assert_eq!(res.keys, vec!["a", "b", "c"]);
+        assert_eq!(res.indexer, vec![1, 4, 0, 2, 3]);
+        assert_eq!(res.bounds, vec![0, 2, 4, 5]);
+
+        assert_eq!(res.get(&"a"), Some(&[1, 4][..]));
+        assert_eq!(res.get(&"b"), Some(&[0, 2][..]));
+        assert_eq!(res.get(&"c"), Some(&[3][..]));
+        assert_eq!(res.get(&"d"), None);
+        assert_eq!(res.slice(1), &[0, 2]);
     }
 
+    #[test]
+    fn test_sort_groupby_sorted() {
+        let key = vec![1, 1, 1, 2, 3, 3];
+        let res: SortGrouper<i32> = SortGrouper::groupby(&key);
+
+        assert_eq!(res.keys, vec![1, 2, 3]);
+        assert_eq!(res.indexer, vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(res.bounds, vec![0, 3, 4, 6]);
+        assert_eq!(res.slice(2), &[4, 5]);
+
+        let res: SortGrouper<i32> = SortGrouper::groupby(&[]);
+        assert_eq!(res.keys, Vec::<i32>::new());
+        assert_eq!(res.bounds, vec![0]);
+    }
 }
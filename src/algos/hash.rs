@@ -0,0 +1,206 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use nullvec::prelude::{Array, Scalar};
+
+use traits::Slicer;
+
+/// Hashable representation of Scalar, used as a key of hash-based algorithms
+/// for mixed dtype values. Integers are unified regardless of their width.
+/// Values are ordered by kind first, and Null is regarded as the largest.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HashableScalar {
+    Int(i64),
+    UInt(u64),
+    // bit representation, NaN is handled as Null in nullvec
+    Float(u64),
+    Bool(bool),
+    Str(String),
+    Null,
+}
+
+impl HashableScalar {
+    pub fn new(value: &Scalar) -> Self {
+        match *value {
+            Scalar::i64(v) => HashableScalar::Int(v),
+            Scalar::i32(v) => HashableScalar::Int(v as i64),
+            Scalar::i16(v) => HashableScalar::Int(v as i64),
+            Scalar::i8(v) => HashableScalar::Int(v as i64),
+            Scalar::isize(v) => HashableScalar::Int(v as i64),
+            Scalar::u64(v) => HashableScalar::UInt(v),
+            Scalar::u32(v) => HashableScalar::UInt(v as u64),
+            Scalar::u16(v) => HashableScalar::UInt(v as u64),
+            Scalar::u8(v) => HashableScalar::UInt(v as u64),
+            Scalar::usize(v) => HashableScalar::UInt(v as u64),
+            Scalar::f64(v) => HashableScalar::from_f64(v),
+            Scalar::f32(v) => HashableScalar::from_f64(v as f64),
+            Scalar::bool(v) => HashableScalar::Bool(v),
+            Scalar::String(ref v) => HashableScalar::Str(v.clone()),
+            Scalar::Null => HashableScalar::Null,
+        }
+    }
+
+    fn from_f64(value: f64) -> Self {
+        if value.is_nan() {
+            HashableScalar::Null
+        } else if value == 0. {
+            // regard -0.0 as 0.0
+            HashableScalar::Float(0f64.to_bits())
+        } else {
+            HashableScalar::Float(value.to_bits())
+        }
+    }
+
+    /// Convert Array to Vec of hashable values
+    pub fn from_array(values: &Array) -> Vec<Self> {
+        (0..values.len())
+            .map(|i| HashableScalar::new(&values.iloc(&i)))
+            .collect()
+    }
+
+    /// Create composite keys per row from multiple Arrays
+    pub fn from_arrays(values: &[&Array]) -> Vec<Vec<Self>> {
+        assert!(!values.is_empty(), "at least one Array is required");
+
+        let len = values[0].len();
+        let mut keys: Vec<Vec<Self>> = (0..len)
+            .map(|_| Vec::with_capacity(values.len()))
+            .collect();
+        for current in values.iter() {
+            assert!(current.len() == len, "Length mismatch!");
+            for (key, value) in keys.iter_mut().zip(HashableScalar::from_array(current)) {
+                key.push(value);
+            }
+        }
+        keys
+    }
+}
+
+impl HashableScalar {
+    fn rank(&self) -> u8 {
+        match *self {
+            HashableScalar::Bool(_) => 0,
+            HashableScalar::Int(_) => 1,
+            HashableScalar::UInt(_) => 2,
+            HashableScalar::Float(_) => 3,
+            HashableScalar::Str(_) => 4,
+            HashableScalar::Null => 5,
+        }
+    }
+}
+
+impl PartialOrd for HashableScalar {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HashableScalar {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (HashableScalar::Bool(l), HashableScalar::Bool(r)) => l.cmp(r),
+            (HashableScalar::Int(l), HashableScalar::Int(r)) => l.cmp(r),
+            (HashableScalar::UInt(l), HashableScalar::UInt(r)) => l.cmp(r),
+            (HashableScalar::Float(l), HashableScalar::Float(r)) => {
+                // NaN never appears
+                f64::from_bits(*l).partial_cmp(&f64::from_bits(*r)).unwrap()
+            }
+            (HashableScalar::Str(l), HashableScalar::Str(r)) => l.cmp(r),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl fmt::Display for HashableScalar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            HashableScalar::Int(ref v) => write!(f, "{}", v),
+            HashableScalar::UInt(ref v) => write!(f, "{}", v),
+            HashableScalar::Float(v) => write!(f, "{}", f64::from_bits(v)),
+            HashableScalar::Bool(ref v) => write!(f, "{}", v),
+            HashableScalar::Str(ref v) => write!(f, "{}", v),
+            HashableScalar::Null => write!(f, "Null"),
+        }
+    }
+}
+
+/// Composite key of multiple values, used as group labels of multiple columns
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HashableTuple {
+    pub values: Vec<HashableScalar>,
+}
+
+impl HashableTuple {
+    pub fn new(values: Vec<HashableScalar>) -> Self {
+        HashableTuple { values }
+    }
+}
+
+impl From<Vec<Scalar>> for HashableTuple {
+    fn from(values: Vec<Scalar>) -> Self {
+        HashableTuple::new(values.iter().map(HashableScalar::new).collect())
+    }
+}
+
+impl fmt::Display for HashableTuple {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let strs: Vec<String> = self.values.iter().map(|x| x.to_string()).collect();
+        write!(f, "({})", strs.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::{Array, Scalar};
+
+    use super::{HashableScalar, HashableTuple};
+
+    #[test]
+    fn test_hashable_scalar() {
+        assert_eq!(HashableScalar::new(&Scalar::i32(1)),
+                   HashableScalar::new(&Scalar::i64(1)));
+        assert_eq!(HashableScalar::new(&Scalar::f64(0.)),
+                   HashableScalar::new(&Scalar::f64(-0.)));
+        assert!(HashableScalar::new(&Scalar::f64(1.)) != HashableScalar::new(&Scalar::i64(1)));
+        assert_eq!(HashableScalar::new(&Scalar::Null), HashableScalar::Null);
+    }
+
+    #[test]
+    fn test_hashable_scalar_from_arrays() {
+        let a1 = Array::new(vec![1i64, 2, 1]);
+        let a2 = Array::new(vec!["a".to_string(), "b".to_string(), "a".to_string()]);
+        let res = HashableScalar::from_arrays(&[&a1, &a2]);
+        assert_eq!(res.len(), 3);
+        assert_eq!(res[0], res[2]);
+        assert_eq!(res[0],
+                   vec![HashableScalar::Int(1), HashableScalar::Str("a".to_string())]);
+    }
+
+    #[test]
+    fn test_hashable_scalar_ord() {
+        let mut values: Vec<HashableScalar> = [Scalar::f64(1.5), Scalar::Null, Scalar::f64(-2.),
+                                               Scalar::f64(0.5)]
+            .iter()
+            .map(HashableScalar::new)
+            .collect();
+        values.sort();
+        let exp: Vec<HashableScalar> = [Scalar::f64(-2.), Scalar::f64(0.5), Scalar::f64(1.5),
+                                        Scalar::Null]
+            .iter()
+            .map(HashableScalar::new)
+            .collect();
+        assert_eq!(values, exp);
+    }
+
+    #[test]
+    fn test_hashable_tuple() {
+        let t1 = HashableTuple::from(vec![Scalar::from("a"), Scalar::i64(1), Scalar::f64(1.5)]);
+        let t2 = HashableTuple::from(vec![Scalar::from("a"), Scalar::i32(1), Scalar::f64(1.5)]);
+        assert_eq!(t1, t2);
+        assert_eq!(t1.to_string(), "(a, 1, 1.5)");
+
+        let t3 = HashableTuple::from(vec![Scalar::from("a"), Scalar::i64(2), Scalar::Null]);
+        assert!(t1 < t3);
+    }
+}
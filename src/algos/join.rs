@@ -3,20 +3,23 @@ use std::cmp;
 use std::hash::Hash;
 use std::usize;
 
-use super::set::{CowCollections, union};
+use nullvec::prelude::dev::algos::Indexing;
 
-const USIZE_MISSING: usize = usize::MAX;
+use super::set::CowCollections;
+
+pub const USIZE_MISSING: usize = usize::MAX;
 
 
 pub struct HashJoin;
 
+/// Join which returns joined keys and locations of each side.
+/// Duplicated keys are joined with all the matched keys in the other (many-to-many),
+/// USIZE_MISSING is used for locations which don't exist
 pub trait JoinOp<T> {
     fn inner(left: &[T], right: &[T]) -> (Vec<T>, Vec<usize>, Vec<usize>);
     fn left(left: &[T], right: &[T]) -> (Vec<T>, Vec<usize>, Vec<usize>);
     fn right(left: &[T], right: &[T]) -> (Vec<T>, Vec<usize>, Vec<usize>);
     fn outer(left: &[T], right: &[T]) -> (Vec<T>, Vec<usize>, Vec<usize>);
-
-    fn keep_first(keep: &[T], other: &[T]) -> (Vec<T>, Vec<usize>, Vec<usize>);
 }
 
 impl<T> JoinOp<T> for HashJoin
@@ -24,104 +27,120 @@ where
     T: Clone + Hash + Eq,
 {
     fn inner(left: &[T], right: &[T]) -> (Vec<T>, Vec<usize>, Vec<usize>) {
-
-        let exp_capacity = cmp::min(left.len(), right.len());
-
-        let mut indexer: Vec<T> = Vec::with_capacity(exp_capacity);
-        let mut lindexer: Vec<usize> = Vec::with_capacity(exp_capacity);
-        let mut rindexer: Vec<usize> = Vec::with_capacity(exp_capacity);
-
-        let map = CowCollections::to_enumhashmap(right);
-
-        // keep left order
-        for (i, key) in left.iter().enumerate() {
-            // ToDo: sort?
-            if let Some(val) = map.get(&Cow::Borrowed(key)) {
-                indexer.push((*key).clone());
-                lindexer.push(i);
-                rindexer.push(*val);
-            }
-        }
+        let (lindexer, rindexer) = HashJoin::merge_inner(left, right);
+        let indexer = Indexing::reindex(left, &lindexer);
         (indexer, lindexer, rindexer)
     }
 
     fn left(left: &[T], right: &[T]) -> (Vec<T>, Vec<usize>, Vec<usize>) {
-        HashJoin::keep_first(left, right)
+        let (lindexer, rindexer) = HashJoin::merge_left(left, right);
+        let indexer = Indexing::reindex(left, &lindexer);
+        (indexer, lindexer, rindexer)
     }
 
     fn right(left: &[T], right: &[T]) -> (Vec<T>, Vec<usize>, Vec<usize>) {
-        let res = HashJoin::keep_first(right, left);
-        (res.0, res.2, res.1)
+        let (lindexer, rindexer) = HashJoin::merge_right(left, right);
+        let indexer = Indexing::reindex(right, &rindexer);
+        (indexer, lindexer, rindexer)
+    }
+
+    fn outer(left: &[T], right: &[T]) -> (Vec<T>, Vec<usize>, Vec<usize>) {
+        let (lindexer, rindexer) = HashJoin::merge_outer(left, right);
+        // take keys from right if it doesn't exist in left
+        let indexer: Vec<T> = lindexer
+            .iter()
+            .zip(&rindexer)
+            .map(|(&l, &r)| if l == USIZE_MISSING {
+                right[r].clone()
+            } else {
+                left[l].clone()
+            })
+            .collect();
+        (indexer, lindexer, rindexer)
     }
+}
 
-    /// internal fn for left or right join
-    /// values in keep is being kept
-    fn keep_first(keep: &[T], other: &[T]) -> (Vec<T>, Vec<usize>, Vec<usize>) {
+/// Join which returns locations only, used when keys are not needed
+pub trait MergeOp<T> {
+    fn merge_inner(left: &[T], right: &[T]) -> (Vec<usize>, Vec<usize>);
+    fn merge_left(left: &[T], right: &[T]) -> (Vec<usize>, Vec<usize>);
+    fn merge_right(left: &[T], right: &[T]) -> (Vec<usize>, Vec<usize>);
+    fn merge_outer(left: &[T], right: &[T]) -> (Vec<usize>, Vec<usize>);
+}
 
-        let exp_capacity = keep.len();
+impl<T> MergeOp<T> for HashJoin
+where
+    T: Clone + Hash + Eq,
+{
+    fn merge_inner(left: &[T], right: &[T]) -> (Vec<usize>, Vec<usize>) {
+        let exp_capacity = cmp::min(left.len(), right.len());
 
-        let mut indexer: Vec<T> = Vec::with_capacity(exp_capacity);
-        let mut kindexer: Vec<usize> = Vec::with_capacity(exp_capacity);
-        let mut oindexer: Vec<usize> = Vec::with_capacity(exp_capacity);
+        let mut lindexer: Vec<usize> = Vec::with_capacity(exp_capacity);
+        let mut rindexer: Vec<usize> = Vec::with_capacity(exp_capacity);
 
-        let map = CowCollections::to_enumhashmap(other);
+        let map = CowCollections::to_multihashmap(right);
 
-        for (i, key) in keep.iter().enumerate() {
-            // ToDo: sort?
-            match map.get(&Cow::Borrowed(key)) {
-                Some(loc) => {
-                    indexer.push((*key).clone());
-                    kindexer.push(i);
-                    oindexer.push(*loc);
-                }
-                None => {
-                    indexer.push((*key).clone());
-                    kindexer.push(i);
-                    oindexer.push(USIZE_MISSING);
+        // keep left order, then right order within the same key
+        for (i, key) in left.iter().enumerate() {
+            if let Some(locs) = map.get(&Cow::Borrowed(key)) {
+                for loc in locs {
+                    lindexer.push(i);
+                    rindexer.push(*loc);
                 }
             }
         }
-        (indexer, kindexer, oindexer)
+        (lindexer, rindexer)
     }
 
-    fn outer(left: &[T], right: &[T]) -> (Vec<T>, Vec<usize>, Vec<usize>) {
-
-        let exp_capacity = cmp::max(left.len(), right.len());
+    fn merge_left(left: &[T], right: &[T]) -> (Vec<usize>, Vec<usize>) {
+        let exp_capacity = left.len();
 
-        // let mut indexer: Vec<T> = Vec::with_capacity(exp_capacity);
         let mut lindexer: Vec<usize> = Vec::with_capacity(exp_capacity);
         let mut rindexer: Vec<usize> = Vec::with_capacity(exp_capacity);
 
-        let lmap = CowCollections::to_enumhashmap(left);
-        let rmap = CowCollections::to_enumhashmap(right);
-
-        let indexer = union(left, right);
+        let map = CowCollections::to_multihashmap(right);
 
-        for key in &indexer {
-            // ToDo: sort?
-            match lmap.get(&Cow::Borrowed(key)) {
-                Some(loc) => {
-                    lindexer.push(*loc);
+        for (i, key) in left.iter().enumerate() {
+            match map.get(&Cow::Borrowed(key)) {
+                Some(locs) => {
+                    for loc in locs {
+                        lindexer.push(i);
+                        rindexer.push(*loc);
+                    }
                 }
-                None => lindexer.push(USIZE_MISSING),
-            }
-            match rmap.get(&Cow::Borrowed(key)) {
-                Some(loc) => {
-                    rindexer.push(*loc);
+                None => {
+                    lindexer.push(i);
+                    rindexer.push(USIZE_MISSING);
                 }
-                None => rindexer.push(USIZE_MISSING),
             }
-
         }
+        (lindexer, rindexer)
+    }
 
-        (indexer, lindexer, rindexer)
+    fn merge_right(left: &[T], right: &[T]) -> (Vec<usize>, Vec<usize>) {
+        let (rindexer, lindexer) = HashJoin::merge_left(right, left);
+        (lindexer, rindexer)
+    }
+
+    fn merge_outer(left: &[T], right: &[T]) -> (Vec<usize>, Vec<usize>) {
+        let (mut lindexer, mut rindexer) = HashJoin::merge_left(left, right);
+
+        // append right keys which don't appear in left
+        let lset = CowCollections::to_hashset(left);
+        for (i, key) in right.iter().enumerate() {
+            if !lset.contains(&Cow::Borrowed(key)) {
+                lindexer.push(USIZE_MISSING);
+                rindexer.push(i);
+            }
+        }
+        (lindexer, rindexer)
     }
 }
 
 #[cfg(test)]
 mod tests {
 
-    use super::{JoinOp, HashJoin, USIZE_MISSING};
+    use super::{JoinOp, MergeOp, HashJoin, USIZE_MISSING};
 
     #[test]
     fn test_vec_inner_join() {
@@ -272,4 +291,54 @@ mod tests {
         assert_eq!(res.1, vec![0, 1, 2, 3, USIZE_MISSING]);
         assert_eq!(res.2, vec![3, USIZE_MISSING, 1, 0, 2]);
     }
+
+    #[test]
+    fn test_vec_merge_inner() {
+        let v1 = vec![1, 2, 1, 3];
+        let v2 = vec![1, 4, 1, 2];
+        let res = HashJoin::merge_inner(&v1, &v2);
+
+        assert_eq!(res.0, vec![0, 0, 1, 2, 2]);
+        assert_eq!(res.1, vec![0, 2, 3, 0, 2]);
+    }
+
+    #[test]
+    fn test_vec_merge_left_right() {
+        let v1 = vec![1, 2, 1, 3];
+        let v2 = vec![1, 4, 1, 2];
+
+        let res = HashJoin::merge_left(&v1, &v2);
+        assert_eq!(res.0, vec![0, 0, 1, 2, 2, 3]);
+        assert_eq!(res.1, vec![0, 2, 3, 0, 2, USIZE_MISSING]);
+
+        let res = HashJoin::merge_right(&v1, &v2);
+        assert_eq!(res.0, vec![0, 2, USIZE_MISSING, 0, 2, 1]);
+        assert_eq!(res.1, vec![0, 0, 1, 2, 2, 3]);
+    }
+
+    #[test]
+    fn test_vec_merge_outer() {
+        let v1 = vec![1, 2, 1, 3];
+        let v2 = vec![1, 4, 1, 2];
+
+        let res = HashJoin::merge_outer(&v1, &v2);
+        assert_eq!(res.0, vec![0, 0, 1, 2, 2, 3, USIZE_MISSING]);
+        assert_eq!(res.1, vec![0, 2, 3, 0, 2, USIZE_MISSING, 1]);
+    }
+
+    #[test]
+    fn test_vec_join_duplicates() {
+        let v1 = vec![1, 2, 1];
+        let v2 = vec![1, 3];
+
+        let res = HashJoin::inner(&v1, &v2);
+        assert_eq!(res.0, vec![1, 1]);
+        assert_eq!(res.1, vec![0, 2]);
+        assert_eq!(res.2, vec![0, 0]);
+
+        let res = HashJoin::outer(&v1, &v2);
+        assert_eq!(res.0, vec![1, 2, 1, 3]);
+        assert_eq!(res.1, vec![0, 1, 2, USIZE_MISSING]);
+        assert_eq!(res.2, vec![0, USIZE_MISSING, 0, 1]);
+    }
 }
@@ -1,6 +1,12 @@
 pub mod computation;
 pub mod counter;
-mod duplicates;
+pub mod duplicates;
+pub mod factorize;
+pub mod fill;
 pub mod grouper;
+pub mod hash;
 pub mod join;
+pub mod sort;
+pub mod transform;
+pub mod window;
 mod set;
@@ -1,6 +1,5 @@
 use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
-use std::collections::hash_map::Entry;
 use std::hash::Hash;
 use std::iter::FromIterator;
 
@@ -8,7 +7,7 @@ pub struct CowCollections;
 
 impl CowCollections {
     /// Create hashset to detect colision, without clone
-    fn to_hashset<T>(a: &[T]) -> HashSet<Cow<T>>
+    pub fn to_hashset<T>(a: &[T]) -> HashSet<Cow<'_, T>>
     where
         T: Clone + Hash + Eq,
     {
@@ -16,21 +15,15 @@ impl CowCollections {
         HashSet::from_iter(a.iter().map(|x| Cow::Borrowed(x)))
     }
 
-    /// Create HashMap<T, usize> from Vec<T> which value is appearance
-    /// location
-    pub fn to_enumhashmap<T>(v: &[T]) -> HashMap<Cow<T>, usize>
+    /// Create HashMap<T, Vec<usize>> from Vec<T> which value is all the
+    /// appearance locations, allowing duplicates
+    pub fn to_multihashmap<T>(v: &[T]) -> HashMap<Cow<'_, T>, Vec<usize>>
     where
         T: Clone + Hash + Eq,
     {
-        // ToDo: Handle duplicates
-
-        let mut map: HashMap<Cow<T>, usize> = HashMap::with_capacity(v.len());
+        let mut map: HashMap<Cow<T>, Vec<usize>> = HashMap::with_capacity(v.len());
         for (i, key) in v.iter().enumerate() {
-            // ToDo: Handle duplicates
-            match map.entry(Cow::Borrowed(key)) {
-                Entry::Occupied(_) => panic!("duplicates are not allowed"),
-                Entry::Vacant(e) => e.insert(i),
-            };
+            map.entry(Cow::Borrowed(key)).or_default().push(i);
         }
         map
     }
@@ -0,0 +1,8 @@
+/// Where to put nulls in sorted result
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum NaPosition {
+    /// put nulls before other values
+    First,
+    /// put nulls after other values
+    Last,
+}
@@ -0,0 +1,215 @@
+use nullvec::prelude::NullVec;
+use nullvec::prelude::dev::NullStorable;
+
+use super::join::USIZE_MISSING;
+use super::window::to_nullvec;
+
+/// Values which can be accumulated. Integer overflow results in None.
+pub trait Accumulate: Copy + PartialOrd + NullStorable {
+    fn acc_add(self, other: Self) -> Option<Self>;
+    fn acc_mul(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_accumulate_int {
+    ($t:ident) => {
+        impl Accumulate for $t {
+            fn acc_add(self, other: Self) -> Option<Self> {
+                self.checked_add(other)
+            }
+
+            fn acc_mul(self, other: Self) -> Option<Self> {
+                self.checked_mul(other)
+            }
+        }
+    }
+}
+impl_accumulate_int!(i64);
+impl_accumulate_int!(i32);
+impl_accumulate_int!(i16);
+impl_accumulate_int!(i8);
+impl_accumulate_int!(isize);
+impl_accumulate_int!(u64);
+impl_accumulate_int!(u32);
+impl_accumulate_int!(u16);
+impl_accumulate_int!(u8);
+impl_accumulate_int!(usize);
+
+macro_rules! impl_accumulate_float {
+    ($t:ident) => {
+        impl Accumulate for $t {
+            fn acc_add(self, other: Self) -> Option<Self> {
+                Some(self + other)
+            }
+
+            fn acc_mul(self, other: Self) -> Option<Self> {
+                Some(self * other)
+            }
+        }
+    }
+}
+impl_accumulate_float!(f64);
+impl_accumulate_float!(f32);
+
+/// Cumulative statistics. Values are passed as Option, None is regarded as null.
+/// Nulls are skipped and kept as null in the result. Once an integer
+/// accumulation overflows, the rest of the result is null.
+pub struct Cumulative;
+
+impl Cumulative {
+    pub fn sum<T: Accumulate>(values: &[Option<T>]) -> NullVec<T> {
+        Cumulative::accumulate(values, &|acc, v| acc.acc_add(v))
+    }
+
+    pub fn prod<T: Accumulate>(values: &[Option<T>]) -> NullVec<T> {
+        Cumulative::accumulate(values, &|acc, v| acc.acc_mul(v))
+    }
+
+    pub fn max<T: Accumulate>(values: &[Option<T>]) -> NullVec<T> {
+        Cumulative::accumulate(values, &|acc, v| Some(if v > acc { v } else { acc }))
+    }
+
+    pub fn min<T: Accumulate>(values: &[Option<T>]) -> NullVec<T> {
+        Cumulative::accumulate(values, &|acc, v| Some(if v < acc { v } else { acc }))
+    }
+
+    fn accumulate<T: Accumulate>(
+        values: &[Option<T>],
+        func: &dyn Fn(T, T) -> Option<T>,
+    ) -> NullVec<T> {
+        let mut current: Option<T> = None;
+        let mut overflow = false;
+        let (new_values, mask): (Vec<T>, Vec<bool>) = values
+            .iter()
+            .map(|value| match *value {
+                Some(v) if !overflow => {
+                    let acc = match current {
+                        Some(acc) => func(acc, v),
+                        None => Some(v),
+                    };
+                    overflow = acc.is_none();
+                    current = acc;
+                    (acc.unwrap_or_default(), overflow)
+                }
+                _ => (T::default(), true),
+            })
+            .unzip();
+        if mask.contains(&true) {
+            NullVec::with_mask(new_values, Some(mask))
+        } else {
+            NullVec::new(new_values)
+        }
+    }
+}
+
+/// Values of NullVec as Option, null and NaN are None
+pub fn to_options<T: Accumulate>(values: &NullVec<T>) -> Vec<Option<T>> {
+    values
+        .iter_raw()
+        .map(|(n, &v)| if n || v.partial_cmp(&v).is_none() { None } else { Some(v) })
+        .collect()
+}
+
+/// Shift values by positions. Positive periods move values forward,
+/// negative periods move values backward.
+pub struct Shift;
+
+impl Shift {
+    /// Return locations to take shifted values from.
+    /// Locations which have no source are USIZE_MISSING.
+    pub fn indexer(len: usize, periods: isize) -> Vec<usize> {
+        (0..len)
+            .map(|i| {
+                // overflow is out of range
+                match (i as isize).checked_sub(periods) {
+                    Some(loc) if loc >= 0 && loc < len as isize => loc as usize,
+                    _ => USIZE_MISSING,
+                }
+            })
+            .collect()
+    }
+
+    /// Difference from the value periods before
+    pub fn diff(values: &[Option<f64>], periods: isize) -> NullVec<f64> {
+        Shift::compare(values, periods, &|current, previous| current - previous)
+    }
+
+    /// Ratio of change from the value periods before
+    pub fn pct_change(values: &[Option<f64>], periods: isize) -> NullVec<f64> {
+        Shift::compare(values, periods, &|current, previous| current / previous - 1.)
+    }
+
+    fn compare(
+        values: &[Option<f64>],
+        periods: isize,
+        func: &dyn Fn(f64, f64) -> f64,
+    ) -> NullVec<f64> {
+        let new_values: Vec<Option<f64>> = Shift::indexer(values.len(), periods)
+            .iter()
+            .zip(values.iter())
+            .map(|(&loc, current)| match (values.get(loc), *current) {
+                (Some(&Some(previous)), Some(current)) => Some(func(current, previous)),
+                _ => None,
+            })
+            .collect();
+        to_nullvec(new_values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::NullVec;
+
+    use super::{Cumulative, Shift};
+    use algos::join::USIZE_MISSING;
+
+    #[test]
+    fn test_cumulative() {
+        let values = vec![Some(2.), None, Some(1.), Some(3.)];
+        let mask = Some(vec![false, true, false, false]);
+
+        let exp = NullVec::with_mask(vec![2., 0., 3., 6.], mask.clone());
+        assert_eq!(Cumulative::sum(&values), exp);
+        let exp = NullVec::with_mask(vec![2., 0., 2., 6.], mask.clone());
+        assert_eq!(Cumulative::prod(&values), exp);
+        let exp = NullVec::with_mask(vec![2., 0., 2., 3.], mask.clone());
+        assert_eq!(Cumulative::max(&values), exp);
+        let exp = NullVec::with_mask(vec![2., 0., 1., 1.], mask.clone());
+        assert_eq!(Cumulative::min(&values), exp);
+    }
+
+    #[test]
+    fn test_cumulative_int_overflow() {
+        let values = vec![Some(100u8), Some(100), None, Some(100)];
+        let exp = NullVec::with_mask(vec![100, 200, 0, 0], Some(vec![false, false, true, true]));
+        assert_eq!(Cumulative::sum(&values), exp);
+        let exp = NullVec::with_mask(vec![100, 0, 0, 0], Some(vec![false, true, true, true]));
+        assert_eq!(Cumulative::prod(&values), exp);
+    }
+
+    #[test]
+    fn test_shift_indexer() {
+        assert_eq!(Shift::indexer(4, 1), vec![USIZE_MISSING, 0, 1, 2]);
+        assert_eq!(Shift::indexer(4, -2), vec![2, 3, USIZE_MISSING, USIZE_MISSING]);
+        assert_eq!(Shift::indexer(2, isize::MIN), vec![USIZE_MISSING, USIZE_MISSING]);
+        assert_eq!(Shift::indexer(2, 3), vec![USIZE_MISSING, USIZE_MISSING]);
+        assert_eq!(Shift::indexer(3, 0), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_diff_pct_change() {
+        let values = vec![Some(1.), Some(2.), None, Some(5.)];
+
+        let exp = NullVec::with_mask(vec![0., 1., 0., 0.], Some(vec![true, false, true, true]));
+        assert_eq!(Shift::diff(&values, 1), exp);
+        let exp = NullVec::with_mask(vec![0., 0., 0., 3.], Some(vec![true, true, true, false]));
+        assert_eq!(Shift::diff(&values, 2), exp);
+        let exp = NullVec::with_mask(vec![-1., 0., 0., 0.], Some(vec![false, true, true, true]));
+        assert_eq!(Shift::diff(&values, -1), exp);
+
+        let exp = NullVec::with_mask(vec![0., 1., 0., 0.], Some(vec![true, false, true, true]));
+        assert_eq!(Shift::pct_change(&values, 1), exp);
+        let exp = NullVec::with_mask(vec![0., 0., 0., 1.5], Some(vec![true, true, true, false]));
+        assert_eq!(Shift::pct_change(&values, 2), exp);
+    }
+}
@@ -0,0 +1,714 @@
+use std::collections::VecDeque;
+
+use nullvec::prelude::{Array, Nullable, NullVec};
+
+use traits::Slicer;
+
+/// Moving window to compute rolling statistics.
+/// Values are passed as Option, None is regarded as null and skipped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Window {
+    /// number of observations in each window
+    pub window: usize,
+    /// minimum number of non-null observations to have a value
+    pub min_periods: usize,
+    /// whether to label the result at the center of window, otherwise at the right edge
+    pub center: bool,
+}
+
+impl Window {
+    pub fn new(window: usize, min_periods: usize, center: bool) -> Self {
+        assert!(window > 0, "window must be positive");
+        assert!(
+            min_periods <= window,
+            "min_periods must be less than or equal to window"
+        );
+        Window {
+            window,
+            min_periods,
+            center,
+        }
+    }
+
+    /// Window which expands to cover all preceding observations
+    pub fn expanding(len: usize, min_periods: usize) -> Self {
+        let window = [len, min_periods, 1].iter().cloned().max().unwrap();
+        Window::new(window, min_periods, false)
+    }
+
+    /// Convert Array to values which can be passed to window functions
+    pub fn from_array(values: &Array) -> Vec<Option<f64>> {
+        (0..values.len())
+            .map(|i| match values.iloc(&i).as_f64() {
+                Nullable::Value(v) if !v.is_nan() => Some(v),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn count(&self, values: &[Option<f64>]) -> NullVec<f64> {
+        self.roll(values, CountState)
+    }
+
+    pub fn sum(&self, values: &[Option<f64>]) -> NullVec<f64> {
+        self.roll(values, SumState::default())
+    }
+
+    pub fn mean(&self, values: &[Option<f64>]) -> NullVec<f64> {
+        self.roll(values, MeanState::default())
+    }
+
+    /// Variance normalized by N - ddof
+    pub fn var(&self, values: &[Option<f64>], ddof: usize) -> NullVec<f64> {
+        self.roll(values, VarState::new(ddof, false))
+    }
+
+    /// Standard deviation normalized by N - ddof
+    pub fn std(&self, values: &[Option<f64>], ddof: usize) -> NullVec<f64> {
+        self.roll(values, VarState::new(ddof, true))
+    }
+
+    pub fn min(&self, values: &[Option<f64>]) -> NullVec<f64> {
+        self.roll(values, MinMaxState::new(false))
+    }
+
+    pub fn max(&self, values: &[Option<f64>]) -> NullVec<f64> {
+        self.roll(values, MinMaxState::new(true))
+    }
+
+    /// Apply passed function to non-null values in each window
+    pub fn apply(&self, values: &[Option<f64>], func: &dyn Fn(&Vec<f64>) -> f64) -> NullVec<f64> {
+        let state = ApplyState {
+            values: VecDeque::with_capacity(self.window),
+            func,
+        };
+        self.roll(values, state)
+    }
+
+    /// Slide window over values, updating state by values entering and
+    /// leaving the window
+    fn roll<S: RollingState>(&self, values: &[Option<f64>], mut state: S) -> NullVec<f64> {
+        // centered result is the trailing result of the window ending offset later
+        let offset = if self.center { (self.window - 1) / 2 } else { 0 };
+
+        let mut new_values: Vec<f64> = Vec::with_capacity(values.len());
+        let mut mask: Vec<bool> = Vec::with_capacity(values.len());
+        let mut nobs: usize = 0;
+
+        for i in 0..(values.len() + offset) {
+            if let Some(&Some(v)) = values.get(i) {
+                state.push(i, v);
+                nobs += 1;
+            }
+            if i >= self.window {
+                if let Some(v) = values[i - self.window] {
+                    state.pop(i - self.window, v);
+                    nobs -= 1;
+                }
+            }
+            if i < offset {
+                continue;
+            }
+            let result = if nobs >= self.min_periods {
+                state.result(nobs)
+            } else {
+                None
+            };
+            match result {
+                Some(v) if !v.is_nan() => {
+                    new_values.push(v);
+                    mask.push(false);
+                }
+                _ => {
+                    new_values.push(0.);
+                    mask.push(true);
+                }
+            }
+        }
+        if mask.contains(&true) {
+            NullVec::with_mask(new_values, Some(mask))
+        } else {
+            NullVec::new(new_values)
+        }
+    }
+}
+
+/// Specification of decay used in exponentially weighted calculations
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Decay {
+    /// smoothing factor directly, 0 < alpha <= 1
+    Alpha(f64),
+    /// alpha = 2 / (span + 1), span >= 1
+    Span(f64),
+    /// alpha = 1 - exp(ln(0.5) / halflife), halflife > 0
+    HalfLife(f64),
+}
+
+impl Decay {
+    pub fn alpha(&self) -> f64 {
+        match *self {
+            Decay::Alpha(alpha) => alpha,
+            Decay::Span(span) => {
+                assert!(span >= 1., "span must be greater than or equal to 1");
+                2. / (span + 1.)
+            }
+            Decay::HalfLife(halflife) => {
+                assert!(halflife > 0., "halflife must be positive");
+                1. - (0.5f64.ln() / halflife).exp()
+            }
+        }
+    }
+}
+
+/// Exponentially weighted moving statistics, compatible with pandas
+/// (ignore_na=False, weights are based on absolute positions).
+/// Values are passed as Option, None is regarded as null and skipped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ExpWeight {
+    /// smoothing factor, 0 < alpha <= 1
+    pub alpha: f64,
+    /// whether to divide by decaying adjustment factor in beginning periods
+    pub adjust: bool,
+}
+
+impl ExpWeight {
+    pub fn new(alpha: f64, adjust: bool) -> Self {
+        assert!(alpha > 0. && alpha <= 1., "alpha must be 0 < alpha <= 1");
+        ExpWeight {
+            alpha,
+            adjust,
+        }
+    }
+
+    pub fn mean(&self, values: &[Option<f64>]) -> NullVec<f64> {
+        let old_wt_factor = 1. - self.alpha;
+        let new_wt = if self.adjust { 1. } else { self.alpha };
+
+        let mut new_values: Vec<Option<f64>> = Vec::with_capacity(values.len());
+        let mut weighted_avg: Option<f64> = None;
+        let mut old_wt = 1.;
+        for value in values.iter() {
+            match (weighted_avg, *value) {
+                (Some(avg), cur) => {
+                    old_wt *= old_wt_factor;
+                    if let Some(cur) = cur {
+                        if avg != cur {
+                            weighted_avg = Some((old_wt * avg + new_wt * cur) / (old_wt + new_wt));
+                        }
+                        old_wt = if self.adjust { old_wt + new_wt } else { 1. };
+                    }
+                }
+                (None, Some(cur)) => weighted_avg = Some(cur),
+                (None, None) => {}
+            }
+            new_values.push(weighted_avg);
+        }
+        to_nullvec(new_values)
+    }
+
+    /// Variance, bias-corrected if bias is false
+    pub fn var(&self, values: &[Option<f64>], bias: bool) -> NullVec<f64> {
+        to_nullvec(self.compute_var(values, bias))
+    }
+
+    /// Standard deviation, bias-corrected if bias is false
+    pub fn std(&self, values: &[Option<f64>], bias: bool) -> NullVec<f64> {
+        let vars = self.compute_var(values, bias);
+        to_nullvec(vars.into_iter().map(|x| x.map(|v| v.sqrt())).collect())
+    }
+
+    fn compute_var(&self, values: &[Option<f64>], bias: bool) -> Vec<Option<f64>> {
+        let old_wt_factor = 1. - self.alpha;
+        let new_wt = if self.adjust { 1. } else { self.alpha };
+
+        let mut new_values: Vec<Option<f64>> = Vec::with_capacity(values.len());
+        let mut mean: Option<f64> = None;
+        let mut cov = 0.;
+        let mut sum_wt = 1.;
+        let mut sum_wt2 = 1.;
+        let mut old_wt = 1.;
+        for value in values.iter() {
+            match (mean, *value) {
+                (Some(old_mean), cur) => {
+                    sum_wt *= old_wt_factor;
+                    sum_wt2 *= old_wt_factor * old_wt_factor;
+                    old_wt *= old_wt_factor;
+                    if let Some(cur) = cur {
+                        let mut new_mean = old_mean;
+                        // avoid numerical errors on constant values
+                        if old_mean != cur {
+                            new_mean = (old_wt * old_mean + new_wt * cur) / (old_wt + new_wt);
+                        }
+                        cov = (old_wt * (cov + (old_mean - new_mean) * (old_mean - new_mean)) +
+                               new_wt * (cur - new_mean) * (cur - new_mean)) /
+                              (old_wt + new_wt);
+                        sum_wt += new_wt;
+                        sum_wt2 += new_wt * new_wt;
+                        old_wt += new_wt;
+                        if !self.adjust {
+                            sum_wt /= old_wt;
+                            sum_wt2 /= old_wt * old_wt;
+                            old_wt = 1.;
+                        }
+                        mean = Some(new_mean);
+                    }
+                }
+                (None, Some(cur)) => mean = Some(cur),
+                (None, None) => {}
+            }
+
+            let result = match mean {
+                None => None,
+                Some(_) if bias => Some(cov),
+                Some(_) => {
+                    let numerator = sum_wt * sum_wt;
+                    let denominator = numerator - sum_wt2;
+                    if denominator > 0. {
+                        Some(numerator / denominator * cov)
+                    } else {
+                        None
+                    }
+                }
+            };
+            new_values.push(result);
+        }
+        new_values
+    }
+}
+
+/// Convert results of window functions to NullVec, None and NaN are regarded as null
+pub fn to_nullvec(values: Vec<Option<f64>>) -> NullVec<f64> {
+    let new_values: Vec<Nullable<f64>> = values
+        .into_iter()
+        .map(|x| match x {
+            Some(v) if !v.is_nan() => Nullable::new(v),
+            _ => Nullable::Null,
+        })
+        .collect();
+    new_values.into()
+}
+
+/// Statistic which can be updated incrementally
+trait RollingState {
+    /// value at loc enters the window
+    fn push(&mut self, loc: usize, value: f64);
+    /// value at loc leaves the window, values leave in the same order as they enter
+    fn pop(&mut self, loc: usize, value: f64);
+    /// current result, nobs is the number of values in the window
+    fn result(&self, nobs: usize) -> Option<f64>;
+}
+
+struct CountState;
+
+impl RollingState for CountState {
+    fn push(&mut self, _: usize, _: f64) {}
+
+    fn pop(&mut self, _: usize, _: f64) {}
+
+    fn result(&self, nobs: usize) -> Option<f64> {
+        Some(nobs as f64)
+    }
+}
+
+/// Sum which can be updated incrementally. Infinities are counted separately
+/// so that the sum recovers once they leave the window
+#[derive(Default)]
+struct RunningSum {
+    finite: f64,
+    pos_inf: usize,
+    neg_inf: usize,
+}
+
+impl RunningSum {
+    fn push(&mut self, value: f64) {
+        if value == f64::INFINITY {
+            self.pos_inf += 1;
+        } else if value == f64::NEG_INFINITY {
+            self.neg_inf += 1;
+        } else {
+            self.finite += value;
+        }
+    }
+
+    fn pop(&mut self, value: f64) {
+        if value == f64::INFINITY {
+            self.pos_inf -= 1;
+        } else if value == f64::NEG_INFINITY {
+            self.neg_inf -= 1;
+        } else {
+            self.finite -= value;
+        }
+    }
+
+    fn get(&self) -> f64 {
+        match (self.pos_inf > 0, self.neg_inf > 0) {
+            (true, true) => f64::NAN,
+            (true, false) => f64::INFINITY,
+            (false, true) => f64::NEG_INFINITY,
+            (false, false) => self.finite,
+        }
+    }
+}
+
+#[derive(Default)]
+struct SumState {
+    sum: RunningSum,
+}
+
+impl RollingState for SumState {
+    fn push(&mut self, _: usize, value: f64) {
+        self.sum.push(value);
+    }
+
+    fn pop(&mut self, _: usize, value: f64) {
+        self.sum.pop(value);
+    }
+
+    fn result(&self, _: usize) -> Option<f64> {
+        Some(self.sum.get())
+    }
+}
+
+#[derive(Default)]
+struct MeanState {
+    sum: RunningSum,
+}
+
+impl RollingState for MeanState {
+    fn push(&mut self, _: usize, value: f64) {
+        self.sum.push(value);
+    }
+
+    fn pop(&mut self, _: usize, value: f64) {
+        self.sum.pop(value);
+    }
+
+    fn result(&self, nobs: usize) -> Option<f64> {
+        if nobs == 0 {
+            None
+        } else {
+            Some(self.sum.get() / nobs as f64)
+        }
+    }
+}
+
+/// Welford's algorithm, supporting removal. While the window contains
+/// non-finite values the result is null, and mean / ssd are recomputed
+/// from the window once the last of them leaves
+struct VarState {
+    ddof: usize,
+    sqrt: bool,
+    nobs: usize,
+    mean: f64,
+    ssd: f64,
+    non_finite: usize,
+    values: VecDeque<f64>,
+}
+
+impl VarState {
+    fn new(ddof: usize, sqrt: bool) -> Self {
+        VarState {
+            ddof,
+            sqrt,
+            nobs: 0,
+            mean: 0.,
+            ssd: 0.,
+            non_finite: 0,
+            values: VecDeque::new(),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.nobs = self.values.len();
+        if self.nobs == 0 {
+            self.mean = 0.;
+            self.ssd = 0.;
+        } else {
+            self.mean = self.values.iter().sum::<f64>() / self.nobs as f64;
+            self.ssd = self.values.iter().map(|v| (v - self.mean) * (v - self.mean)).sum();
+        }
+    }
+}
+
+impl RollingState for VarState {
+    fn push(&mut self, _: usize, value: f64) {
+        self.values.push_back(value);
+        if !value.is_finite() {
+            self.non_finite += 1;
+            return;
+        }
+        if self.non_finite > 0 {
+            return;
+        }
+        self.nobs += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.nobs as f64;
+        self.ssd += delta * (value - self.mean);
+    }
+
+    fn pop(&mut self, _: usize, value: f64) {
+        self.values.pop_front();
+        if !value.is_finite() {
+            self.non_finite -= 1;
+            if self.non_finite == 0 {
+                self.reset();
+            }
+            return;
+        }
+        if self.non_finite > 0 {
+            return;
+        }
+        self.nobs -= 1;
+        if self.nobs == 0 {
+            self.mean = 0.;
+            self.ssd = 0.;
+        } else {
+            let delta = value - self.mean;
+            self.mean -= delta / self.nobs as f64;
+            self.ssd -= delta * (value - self.mean);
+        }
+    }
+
+    fn result(&self, nobs: usize) -> Option<f64> {
+        if nobs <= self.ddof || self.non_finite > 0 {
+            return None;
+        }
+        // cancel small negative rounding error, NaN is kept
+        let tolerance = 16. * f64::EPSILON * nobs as f64 * (self.mean * self.mean).max(1.);
+        let ssd = if self.ssd < 0. && self.ssd > -tolerance { 0. } else { self.ssd };
+        let var = ssd / (nobs - self.ddof) as f64;
+        if self.sqrt { Some(var.sqrt()) } else { Some(var) }
+    }
+}
+
+/// Monotonic deque, the front is the current min (max)
+struct MinMaxState {
+    is_max: bool,
+    deque: VecDeque<(usize, f64)>,
+}
+
+impl MinMaxState {
+    fn new(is_max: bool) -> Self {
+        MinMaxState {
+            is_max,
+            deque: VecDeque::new(),
+        }
+    }
+}
+
+impl RollingState for MinMaxState {
+    fn push(&mut self, loc: usize, value: f64) {
+        while let Some(&(_, last)) = self.deque.back() {
+            let dominated = if self.is_max { last <= value } else { last >= value };
+            if !dominated {
+                break;
+            }
+            self.deque.pop_back();
+        }
+        self.deque.push_back((loc, value));
+    }
+
+    fn pop(&mut self, loc: usize, _: f64) {
+        if let Some(&(first, _)) = self.deque.front() {
+            if first == loc {
+                self.deque.pop_front();
+            }
+        }
+    }
+
+    fn result(&self, _: usize) -> Option<f64> {
+        self.deque.front().map(|&(_, v)| v)
+    }
+}
+
+struct ApplyState<'f> {
+    values: VecDeque<f64>,
+    func: &'f dyn Fn(&Vec<f64>) -> f64,
+}
+
+impl<'f> RollingState for ApplyState<'f> {
+    fn push(&mut self, _: usize, value: f64) {
+        self.values.push_back(value);
+    }
+
+    fn pop(&mut self, _: usize, _: f64) {
+        self.values.pop_front();
+    }
+
+    fn result(&self, nobs: usize) -> Option<f64> {
+        if nobs == 0 {
+            return None;
+        }
+        let values: Vec<f64> = self.values.iter().cloned().collect();
+        Some((self.func)(&values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::NullVec;
+
+    use super::{Window, Decay, ExpWeight};
+
+    fn nullvec(values: Vec<f64>, mask: Vec<bool>) -> NullVec<f64> {
+        NullVec::with_mask(values, Some(mask))
+    }
+
+    #[test]
+    fn test_rolling_sum_mean() {
+        let values = vec![Some(1.), Some(2.), Some(3.), Some(4.), Some(5.)];
+        let w = Window::new(3, 3, false);
+        assert_eq!(w.sum(&values), nullvec(vec![0., 0., 6., 9., 12.],
+                                           vec![true, true, false, false, false]));
+        assert_eq!(w.mean(&values), nullvec(vec![0., 0., 2., 3., 4.],
+                                            vec![true, true, false, false, false]));
+
+        let w = Window::new(3, 1, false);
+        assert_eq!(w.sum(&values), NullVec::new(vec![1., 3., 6., 9., 12.]));
+        assert_eq!(w.count(&values), NullVec::new(vec![1., 2., 3., 3., 3.]));
+    }
+
+    #[test]
+    fn test_rolling_sum_inf() {
+        // sum recovers after infinity leaves the window
+        let values = vec![Some(1.), Some(f64::INFINITY), Some(2.), Some(3.),
+                          Some(f64::NEG_INFINITY)];
+        let w = Window::new(2, 1, false);
+        let exp = vec![1., f64::INFINITY, f64::INFINITY, 5., f64::NEG_INFINITY];
+        assert_eq!(w.sum(&values), NullVec::new(exp));
+        let exp = vec![1., f64::INFINITY, f64::INFINITY, 2.5, f64::NEG_INFINITY];
+        assert_eq!(w.mean(&values), NullVec::new(exp));
+    }
+
+    #[test]
+    fn test_rolling_var_inf() {
+        // variance recovers after infinity leaves the window
+        let values = vec![Some(1.), Some(f64::INFINITY), Some(2.), Some(3.), Some(4.), Some(5.)];
+        let w = Window::new(2, 1, false);
+        let exp = nullvec(vec![0., 0., 0., 0.5, 0.5, 0.5],
+                          vec![true, true, true, false, false, false]);
+        assert_eq!(w.var(&values, 1), exp);
+
+        let w = Window::new(3, 1, false);
+        let res = w.var(&values, 0);
+        assert_eq!(res.is_null(), vec![false, true, true, true, false, false]);
+        let res: Vec<f64> = res.iter_not_null().cloned().collect();
+        assert_eq!(res[0], 0.);
+        assert!((res[1] - 2. / 3.).abs() < 1e-10);
+        assert!((res[2] - 2. / 3.).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_rolling_center() {
+        let values = vec![Some(1.), Some(2.), Some(3.), Some(4.), Some(5.)];
+        let w = Window::new(3, 3, true);
+        assert_eq!(w.sum(&values), nullvec(vec![0., 6., 9., 12., 0.],
+                                           vec![true, false, false, false, true]));
+
+        let w = Window::new(3, 1, true);
+        assert_eq!(w.max(&values), NullVec::new(vec![2., 3., 4., 5., 5.]));
+    }
+
+    #[test]
+    fn test_rolling_null() {
+        let values = vec![Some(1.), None, Some(3.), Some(4.), None, None];
+        let w = Window::new(2, 1, false);
+        assert_eq!(w.sum(&values), nullvec(vec![1., 1., 3., 7., 4., 0.],
+                                           vec![false, false, false, false, false, true]));
+        assert_eq!(w.min(&values), nullvec(vec![1., 1., 3., 3., 4., 0.],
+                                           vec![false, false, false, false, false, true]));
+    }
+
+    #[test]
+    fn test_rolling_min_max() {
+        let values = vec![Some(3.), Some(1.), Some(4.), Some(1.), Some(5.), Some(9.), Some(2.)];
+        let w = Window::new(3, 3, false);
+        assert_eq!(w.min(&values), nullvec(vec![0., 0., 1., 1., 1., 1., 2.],
+                                           vec![true, true, false, false, false, false, false]));
+        assert_eq!(w.max(&values), nullvec(vec![0., 0., 4., 4., 5., 9., 9.],
+                                           vec![true, true, false, false, false, false, false]));
+    }
+
+    #[test]
+    fn test_rolling_var() {
+        let values = vec![Some(1.), Some(2.), Some(4.), Some(8.)];
+        let w = Window::new(3, 2, false);
+        let res = w.var(&values, 1);
+        assert_eq!(res.is_null(), vec![true, false, false, false]);
+        let res: Vec<f64> = res.iter_not_null().cloned().collect();
+        let exp = [0.5, 7. / 3., 28. / 3.];
+        for (r, e) in res.iter().zip(exp.iter()) {
+            assert!((r - e).abs() < 1e-10);
+        }
+
+        let res: Vec<f64> = w.std(&values, 0).iter_not_null().cloned().collect();
+        assert!((res[2] - (56f64 / 9.).sqrt()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_rolling_apply() {
+        let values = vec![Some(1.), Some(5.), Some(2.), Some(3.)];
+        let w = Window::new(2, 2, false);
+        let range = |x: &Vec<f64>| x[1] - x[0];
+        assert_eq!(w.apply(&values, &range), nullvec(vec![0., 4., -3., 1.],
+                                                     vec![true, false, false, false]));
+    }
+
+    #[test]
+    fn test_expanding() {
+        let values = vec![Some(2.), None, Some(1.), Some(3.)];
+        let w = Window::expanding(values.len(), 1);
+        assert_eq!(w.mean(&values), NullVec::new(vec![2., 2., 1.5, 2.]));
+        assert_eq!(w.min(&values), NullVec::new(vec![2., 2., 1., 1.]));
+    }
+
+    #[test]
+    fn test_decay() {
+        assert_eq!(Decay::Alpha(0.3).alpha(), 0.3);
+        assert_eq!(Decay::Span(3.).alpha(), 0.5);
+        assert!((Decay::HalfLife(1.).alpha() - 0.5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_ewm_mean() {
+        let values = vec![Some(1.), Some(2.), None, Some(4.)];
+
+        // weights are (1 - alpha) ** i
+        let ew = ExpWeight::new(0.5, true);
+        let exp = [1., 5. / 3., 5. / 3., (0.125 + 0.5 + 4.) / (0.125 + 0.25 + 1.)];
+        let res: Vec<f64> = ew.mean(&values).iter_not_null().cloned().collect();
+        for (r, e) in res.iter().zip(exp.iter()) {
+            assert!((r - e).abs() < 1e-10);
+        }
+
+        let ew = ExpWeight::new(0.5, false);
+        let exp = [1., 1.5, 1.5, (0.25 * 1.5 + 0.5 * 4.) / 0.75];
+        let res: Vec<f64> = ew.mean(&values).iter_not_null().cloned().collect();
+        for (r, e) in res.iter().zip(exp.iter()) {
+            assert!((r - e).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_ewm_var() {
+        let values = vec![Some(1.), Some(3.), Some(5.)];
+        let ew = ExpWeight::new(0.5, true);
+
+        let res = ew.var(&values, true);
+        assert!(!res.has_null());
+        let res: Vec<f64> = res.iter_not_null().cloned().collect();
+        // weighted variance with weights 0.5, 1
+        let mean = (0.5 + 3.) / 1.5;
+        let exp = (0.5 * (1. - mean) * (1. - mean) + (3. - mean) * (3. - mean)) / 1.5;
+        assert!((res[0] - 0.).abs() < 1e-10);
+        assert!((res[1] - exp).abs() < 1e-10);
+
+        let res = ew.var(&values, false);
+        assert_eq!(res.is_null(), vec![true, false, false]);
+        let res: Vec<f64> = res.iter_not_null().cloned().collect();
+        // bias correction: sum(w) ** 2 / (sum(w) ** 2 - sum(w ** 2))
+        let factor = 1.5 * 1.5 / (1.5 * 1.5 - 1.25);
+        assert!((res[0] - exp * factor).abs() < 1e-10);
+    }
+}
@@ -1,4 +1,4 @@
-use num::{Num, Zero, ToPrimitive};
+use num::{Zero, ToPrimitive};
 use std::borrow::{Borrow, Cow};
 use std::hash::Hash;
 use std::ops::{Add, Sub, Div};
@@ -0,0 +1,58 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use nullvec::prelude::Array;
+
+use super::Block;
+use error::Error;
+use frame::DataFrame;
+use indexer::Indexer;
+use traits::ArrayElement;
+
+// /////////////////////////////////////////////////////////////////////////////
+// From / Into
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, 'c, V, I, C> From<Block<'v, 'i, 'c, V, I, C>> for DataFrame<'v, 'i, 'c, I, C>
+    where V: Clone,
+          I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash,
+          Array: From<Vec<V>>
+{
+    fn from(block: Block<'v, 'i, 'c, V, I, C>) -> Self {
+        let new_values: Vec<Cow<Array>> = block.values
+            .into_iter()
+            .map(|x| Cow::Owned(Array::from(x.into_owned())))
+            .collect();
+        DataFrame {
+            values: new_values,
+            index: block.index,
+            columns: block.columns,
+        }
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+    where I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash
+{
+    /// Convert to single-dtype Block, Err if any column has different dtype
+    pub fn as_block<V>(&self) -> Result<Block<'v, 'i, 'c, V, I, C>, Error>
+        where V: 'v + ArrayElement
+    {
+        let mut new_values: Vec<Cow<Vec<V>>> = Vec::with_capacity(self.values.len());
+        for current in self.values.iter() {
+            new_values.push(Cow::Owned(V::from_array(current)?));
+        }
+        // keep borrowing the original index / columns if possible
+        let new_index: Cow<'i, Indexer<I>> = match self.index {
+            Cow::Borrowed(index) => Cow::Borrowed(index),
+            Cow::Owned(ref index) => Cow::Owned(index.clone()),
+        };
+        let new_columns: Cow<'c, Indexer<C>> = match self.columns {
+            Cow::Borrowed(columns) => Cow::Borrowed(columns),
+            Cow::Owned(ref columns) => Cow::Owned(columns.clone()),
+        };
+        Ok(Block::from_cow(new_values, new_index, new_columns))
+    }
+}
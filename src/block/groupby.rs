@@ -1,25 +1,23 @@
 
-use num::{Num, Zero, ToPrimitive};
+use num::{Zero, ToPrimitive};
 
-use std::cmp::Ord;
 use std::hash::Hash;
 use std::ops::{Add, Sub, Div};
 
 use super::Block;
 use algos::computation::NanMinMax;
-use algos::grouper::Grouper;
 use groupby::GroupBy;
 use traits::{Apply, BasicAggregation, NumericAggregation, ComparisonAggregation};
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Apply
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Apply
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<'v, 'i, 'c, V, I, C, G, W> Apply<'c, Vec<W>> for GroupBy<'i, Block<'v, 'i, 'c, V, I, C>, G>
     where V: Clone,
           I: Clone + Eq + Hash,
           C: Clone + Eq + Hash,
-          G: 'c + Clone + Eq + Hash + Ord,
+          G: 'c + Clone + Eq + Hash,
           W: 'c + Clone
 {
     type In = Block<'v, 'i, 'c, V, I, C>;
@@ -27,13 +25,12 @@ impl<'v, 'i, 'c, V, I, C, G, W> Apply<'c, Vec<W>> for GroupBy<'i, Block<'v, 'i,
     type Out = Block<'c, 'c, 'c, W, G, C>;
 
     /// Apply passed function to each group
-    fn apply<'f>(&'c self, func: &'f Fn(&Self::In) -> Self::FOut) -> Self::Out {
-
-        let mut new_values: Vec<W> = Vec::with_capacity(self.grouper.len());
+    fn apply<'f>(&'c self, func: &'f dyn Fn(&Self::In) -> Self::FOut) -> Self::Out {
 
         let groups = self.groups();
+        let mut new_values: Vec<W> = Vec::with_capacity(groups.len());
         for g in groups.iter() {
-            let s = self.get_group(&g);
+            let s = self.get_group(g);
             new_values.append(&mut func(&s));
         }
         let new_columns = self.data.columns.clone();
@@ -41,15 +38,15 @@ impl<'v, 'i, 'c, V, I, C, G, W> Apply<'c, Vec<W>> for GroupBy<'i, Block<'v, 'i,
     }
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Aggregation
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Aggregation
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<'v, 'i, 'c, V, I, C, G> BasicAggregation<'c> for GroupBy<'i, Block<'v, 'i, 'c, V, I, C>, G>
     where V: 'c + Clone + Zero + Add,
           I: Clone + Eq + Hash,
           C: Clone + Eq + Hash,
-          G: 'c + Clone + Eq + Hash + Ord
+          G: 'c + Clone + Eq + Hash
 {
     type Kept = Block<'c, 'c, 'c, V, G, C>;
     type Counted = Block<'c, 'c, 'c, usize, G, C>;
@@ -67,7 +64,7 @@ impl<'v, 'i, 'c, V, I, C, G> NumericAggregation<'c> for GroupBy<'i, Block<'v, 'i
     where V: 'c + Clone + Zero + Add + Sub + Div + ToPrimitive,
           I: Clone + Eq + Hash,
           C: Clone + Eq + Hash,
-          G: 'c + Clone + Eq + Hash + Ord
+          G: 'c + Clone + Eq + Hash
 {
     type Coerced = Block<'c, 'c, 'c, f64, G, C>;
 
@@ -98,7 +95,7 @@ impl<'v, 'i, 'c, V, I, C, G> ComparisonAggregation<'c>
     where V: 'c + Clone + NanMinMax<V>,
           I: Clone + Eq + Hash,
           C: Clone + Eq + Hash,
-          G: 'c + Clone + Eq + Hash + Ord
+          G: 'c + Clone + Eq + Hash
 {
     type Kept = Block<'c, 'c, 'c, V, G, C>;
 
@@ -4,13 +4,14 @@ use std::slice;
 use std::vec;
 
 use nullvec::prelude::dev::algos::Indexing;
-use nullvec::prelude::dev::algos::Sorter;
+use error::Error;
 use indexer::Indexer;
 use groupby::GroupBy;
 use series::Series;
 use traits::{Slicer, IndexerIndex, RowIndex, ColIndex, Apply};
 
 mod aggregation;
+mod convert;
 mod formatting;
 mod groupby;
 mod ops;
@@ -31,28 +32,36 @@ pub struct Block<'v, 'i, 'c, V, I, C>
     pub columns: Cow<'c, Indexer<C>>,
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Indexing
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Indexing
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<'v, 'i, 'c, V, I, C> RowIndex<'c> for Block<'v, 'i, 'c, V, I, C>
-    where V: Clone,
+    where V: 'c + Clone,
           I: Clone + Eq + Hash,
           C: Clone + Eq + Hash
 {
     type Key = I;
-    type Row = V;
+    type Row = Series<'c, 'c, V, C>;
 
     fn len(&'c self) -> usize {
         self.index.len()
     }
 
     fn loc<'l>(&'c self, label: &'l Self::Key) -> Self::Row {
-        unimplemented!()
+        let loc = self.index.get_loc(label);
+        self.iloc(&loc)
+    }
+
+    fn try_loc<'l>(&'c self, label: &'l Self::Key) -> Result<Self::Row, Error> {
+        let loc = self.index.try_get_loc(label)?;
+        Ok(self.iloc(&loc))
     }
 
-    fn iloc<'l>(&'c self, locaiton: &'l usize) -> Self::Row {
-        unimplemented!()
+    fn iloc<'l>(&'c self, location: &'l usize) -> Self::Row {
+        let new_values: Vec<V> = self.values.iter().map(|x| x[*location].clone()).collect();
+        Series::from_cow(Cow::Owned(new_values),
+                         Cow::Borrowed(self.columns.borrow()))
     }
 
     fn reindex<'l>(&'c self, labels: &'l [Self::Key]) -> Self {
@@ -74,9 +83,16 @@ impl<'v, 'i, 'c, V, I, C> RowIndex<'c> for Block<'v, 'i, 'c, V, I, C>
                         Cow::Borrowed(self.columns.borrow()))
     }
 
-    fn blocs<'l>(&'c self, labels: &'l [bool]) -> Self {
-        unimplemented!()
-        // ToDo: fix Series impl
+    fn blocs<'l>(&'c self, flags: &'l [bool]) -> Self {
+        let new_index = self.index.blocs(flags);
+
+        let mut new_values: Vec<Cow<Vec<V>>> = Vec::with_capacity(self.columns.len());
+        for current in self.values.iter() {
+            new_values.push(Cow::Owned(Indexing::blocs(current, flags)));
+        }
+        Block::from_cow(new_values,
+                        Cow::Owned(new_index),
+                        Cow::Borrowed(self.columns.borrow()))
     }
 }
 
@@ -118,9 +134,9 @@ impl<'v, 'i, 'c, V, I, C> ColIndex<'i> for Block<'v, 'i, 'c, V, I, C>
     }
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Misc
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Misc
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<'v, 'i, 'c, V, I, C> Block<'v, 'i, 'c, V, I, C>
     where V: Clone,
@@ -143,7 +159,7 @@ impl<'v, 'i, 'c, V, I, C> Block<'v, 'i, 'c, V, I, C>
 
         let mut new_values: Vec<Cow<Vec<V>>> = Vec::with_capacity(columns.len());
         for value in values.chunks(len) {
-            let v: Vec<V> = value.iter().cloned().collect();
+            let v: Vec<V> = value.to_vec();
             new_values.push(Cow::Owned(v));
         }
         Block {
@@ -200,10 +216,10 @@ impl<'v, 'i, 'c, V, I, C> Block<'v, 'i, 'c, V, I, C>
             assert!(value.len() == len, "Length mismatch!");
         }
         let values: Vec<Cow<Vec<V>>> = values.into_iter()
-            .map(|x| Cow::Owned(x))
+            .map(Cow::Owned)
             .collect();
         Block {
-            values: values,
+            values,
             index: Cow::Owned(index),
             columns: Cow::Owned(columns),
         }
@@ -217,14 +233,16 @@ impl<'v, 'i, 'c, V, I, C> Block<'v, 'i, 'c, V, I, C>
         Block::from_vec(values, index, columns)
     }
 
+    // values are stored as Cow<Vec<V>>, the same as Series
+    #[allow(clippy::owned_cow)]
     fn from_cow(values: Vec<Cow<'v, Vec<V>>>,
                 index: Cow<'i, Indexer<I>>,
                 columns: Cow<'c, Indexer<C>>)
                 -> Self {
         Block {
-            values: values,
-            index: index,
-            columns: columns,
+            values,
+            index,
+            columns,
         }
     }
 
@@ -237,7 +255,7 @@ impl<'v, 'i, 'c, V, I, C> Block<'v, 'i, 'c, V, I, C>
         columns.push(name);
 
         Block {
-            values: values,
+            values,
             index: Cow::Owned(series.index.into_owned()),
             columns: Cow::Owned(columns),
         }
@@ -255,10 +273,10 @@ impl<'v, 'i, 'c, V, I, C> Block<'v, 'i, 'c, V, I, C>
         self.columns.to_mut().push(name);
     }
 
-    pub fn groupby<G>(&self, other: Vec<G>) -> GroupBy<Block<V, I, C>, G>
-        where G: Clone + Eq + Hash + Ord
+    pub fn groupby<'a, G>(&'a self, other: &[G]) -> GroupBy<'a, Block<'a, 'a, 'a, V, I, C>, G>
+        where G: 'a + Clone + Eq + Hash + Ord
     {
-        GroupBy::new(&self, other)
+        GroupBy::new(self, other)
     }
 
     pub fn transpose(&'i self) -> Block<'i, 'i, 'i, V, C, I> {
@@ -277,9 +295,9 @@ impl<'v, 'i, 'c, V, I, C> Block<'v, 'i, 'c, V, I, C>
     }
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Apply
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Apply
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<'v, 'i, 'c, V, I, C, R> Apply<'i, R> for Block<'v, 'i, 'c, V, I, C>
     where V: Clone,
@@ -292,18 +310,18 @@ impl<'v, 'i, 'c, V, I, C, R> Apply<'i, R> for Block<'v, 'i, 'c, V, I, C>
     // ToDo: use 'n lifetime for values
     type Out = Series<'i, 'i, R, C>;
 
-    fn apply<'f>(&'i self, func: &'f Fn(&Self::In) -> Self::FOut) -> Self::Out {
+    fn apply<'f>(&'i self, func: &'f dyn Fn(&Self::In) -> Self::FOut) -> Self::Out {
         let mut new_values = vec![];
         for current in self.values.iter() {
-            new_values.push(func(&current));
+            new_values.push(func(current));
         }
         Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(self.columns.borrow()))
     }
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Eq
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Eq
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<'v, 'i, 'c, V, I, C> PartialEq for Block<'v, 'i, 'c, V, I, C>
     where V: Clone + PartialEq,
@@ -316,9 +334,9 @@ impl<'v, 'i, 'c, V, I, C> PartialEq for Block<'v, 'i, 'c, V, I, C>
     }
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Iterator
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Iterator
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<'v, 'i, 'c, V, I, C> IntoIterator for Block<'v, 'i, 'c, V, I, C>
     where V: Clone,
@@ -339,7 +357,7 @@ impl<'v, 'i, 'c, V, I, C> Block<'v, 'i, 'c, V, I, C>
           I: Clone + Hash + Eq,
           C: Clone + Hash + Eq
 {
-    pub fn iter(&self) -> slice::Iter<Cow<Vec<V>>> {
+    pub fn iter(&self) -> slice::Iter<'_, Cow<'_, Vec<V>>> {
         self.values.iter()
     }
 }
@@ -234,7 +234,8 @@ mod tests {
         let res = &b + 3;
         assert_eq!(res, exp);
 
-        let res = &b + &3;
+        let three = &3;
+        let res = &b + three;
         assert_eq!(res, exp);
     }
 
@@ -243,7 +244,8 @@ mod tests {
         let exp = Block::from_col_vec(vec![4, 5, 6, 7, 8, 9], vec![10, 20, 30], vec!["X", "Y"]);
 
         let b = Block::from_col_vec(vec![1, 2, 3, 4, 5, 6], vec![10, 20, 30], vec!["X", "Y"]);
-        let res = b + &3;
+        let three = &3;
+        let res = b + three;
         assert_eq!(res, exp);
     }
 
@@ -308,7 +310,8 @@ mod tests {
         let res = &b + 3.;
         assert_eq!(res, exp);
 
-        let res = &b + &3.;
+        let three = &3.;
+        let res = &b + three;
         assert_eq!(res, exp);
     }
 
@@ -321,7 +324,8 @@ mod tests {
         let b = Block::from_col_vec(vec![1., 2., 3., 4., 5., 6.],
                                     vec![10, 20, 30],
                                     vec!["X", "Y"]);
-        let res = b + &3.;
+        let three = &3.;
+        let res = b + three;
         assert_eq!(res, exp);
     }
 
@@ -1,10 +1,14 @@
 use std::borrow::{Borrow, Cow};
 use std::hash::Hash;
 
+use nullvec::prelude::Array;
+
 use super::Block;
 use algos::join::{JoinOp, HashJoin};
+use error::Error;
+use frame::DataFrame;
 use indexer::Indexer;
-use traits::{Slicer, IndexerIndex, RowIndex, Append, Concatenation, Join};
+use traits::{Slicer, RowIndex, Append, Concatenation, Join};
 
 
 impl<'v, 'i, 'c, V, I, C> Append<'c> for Block<'v, 'i, 'c, V, I, C>
@@ -29,6 +33,13 @@ impl<'v, 'i, 'c, V, I, C> Append<'c> for Block<'v, 'i, 'c, V, I, C>
                         Cow::Owned(new_index),
                         Cow::Borrowed(self.columns.borrow()))
     }
+
+    fn try_append<'o>(&'c self, other: &'o Self) -> Result<Self, Error> {
+        if self.columns != other.columns {
+            return Err(Error::ColumnsMismatch);
+        }
+        Ok(self.append(other))
+    }
 }
 
 impl<'v, 'i, 'c, V, I, C> Concatenation<'i> for Block<'v, 'i, 'c, V, I, C>
@@ -52,13 +63,25 @@ impl<'v, 'i, 'c, V, I, C> Concatenation<'i> for Block<'v, 'i, 'c, V, I, C>
                         Cow::Borrowed(self.index.borrow()),
                         Cow::Owned(new_columns))
     }
+
+    fn try_concat<'o>(&'i self, other: &'o Self) -> Result<Self, Error> {
+        if self.index != other.index {
+            return Err(Error::IndexMismatch);
+        }
+        Ok(self.concat(other))
+    }
 }
 
 impl<'v, 'i, 'c, V, I, C> Join for Block<'v, 'i, 'c, V, I, C>
     where V: Clone,
           I: Clone + Eq + Hash,
-          C: Clone + Eq + Hash
+          C: Clone + Eq + Hash,
+          Array: From<Vec<V>>
 {
+    type Output = Self;
+    // Vec<V> cannot hold null, result is DataFrame backed by Array
+    type NullableOutput = DataFrame<'v, 'i, 'c, I, C>;
+
     fn join_inner(&self, other: &Self) -> Self {
 
         let (new_index, lindexer, rindexer) = HashJoin::inner(&self.index.values,
@@ -78,4 +101,51 @@ impl<'v, 'i, 'c, V, I, C> Join for Block<'v, 'i, 'c, V, I, C>
                         Cow::Owned(Indexer::new(new_index)),
                         Cow::Owned(new_columns))
     }
+
+    fn join_left(&self, other: &Self) -> Self::NullableOutput {
+        let (new_index, lindexer, rindexer) = HashJoin::left(&self.index.values,
+                                                             &other.index.values);
+        self.join_forced(other, new_index, &lindexer, &rindexer)
+    }
+
+    fn join_right(&self, other: &Self) -> Self::NullableOutput {
+        let (new_index, lindexer, rindexer) = HashJoin::right(&self.index.values,
+                                                              &other.index.values);
+        self.join_forced(other, new_index, &lindexer, &rindexer)
+    }
+
+    fn join_outer(&self, other: &Self) -> Self::NullableOutput {
+        let (new_index, lindexer, rindexer) = HashJoin::outer(&self.index.values,
+                                                              &other.index.values);
+        self.join_forced(other, new_index, &lindexer, &rindexer)
+    }
+}
+
+impl<'v, 'i, 'c, V, I, C> Block<'v, 'i, 'c, V, I, C>
+    where V: Clone,
+          I: Clone + Eq + Hash,
+          C: Clone + Eq + Hash,
+          Array: From<Vec<V>>
+{
+    /// Join using indexers returned from JoinOp, missing locations are filled with null
+    fn join_forced(&self,
+                   other: &Self,
+                   new_index: Vec<I>,
+                   lindexer: &[usize],
+                   rindexer: &[usize])
+                   -> DataFrame<'v, 'i, 'c, I, C> {
+        let new_columns = self.columns.append(&other.columns);
+
+        let mut new_values: Vec<Array> = Vec::with_capacity(new_columns.len());
+
+        // ToDo: avoid clone
+        for values in &self.values {
+            new_values.push(Array::from(values.clone().into_owned()).ilocs_forced(lindexer));
+        }
+        for values in &other.values {
+            new_values.push(Array::from(values.clone().into_owned()).ilocs_forced(rindexer));
+        }
+
+        DataFrame::from_vec(new_values, new_index, new_columns)
+    }
 }
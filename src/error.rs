@@ -0,0 +1,45 @@
+//! Error returned by fallible (try_) methods
+
+use std::error;
+use std::fmt;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Error {
+    /// Length is different from expected, (expected, actual)
+    LengthMismatch(usize, usize),
+    /// Index of both sides must be identical
+    IndexMismatch,
+    /// Columns of both sides must be identical
+    ColumnsMismatch,
+    /// dtype is different from expected, (expected, actual)
+    DtypeMismatch(String, String),
+    /// Label doesn't exist in the index
+    LabelNotFound,
+    /// Label appears more than once and cannot be located uniquely
+    DuplicatedLabel,
+    /// Group doesn't exist in the GroupBy
+    GroupNotFound,
+    /// Operation is not supported for the values
+    InvalidOperation(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::LengthMismatch(expected, actual) => {
+                write!(f, "Length mismatch! (expected: {}, actual: {})", expected, actual)
+            }
+            Error::IndexMismatch => write!(f, "index must be identical"),
+            Error::ColumnsMismatch => write!(f, "columns must be identical"),
+            Error::DtypeMismatch(ref expected, ref actual) => {
+                write!(f, "dtype mismatch (expected: {}, actual: {})", expected, actual)
+            }
+            Error::LabelNotFound => write!(f, "label not found"),
+            Error::DuplicatedLabel => write!(f, "label is duplicated, use get_all_locs"),
+            Error::GroupNotFound => write!(f, "Group not found!"),
+            Error::InvalidOperation(ref message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl error::Error for Error {}
@@ -0,0 +1,355 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Scalar, Nullable, NullVec};
+use nullvec::prelude::dev::{Stringify, NullStorable};
+
+use algos::factorize::Factorize;
+use error::Error;
+use formatting;
+use indexer::Indexer;
+use series::Series;
+use traits::{Slicer, IndexerIndex, RowIndex, ArrayElement};
+
+#[derive(Clone)]
+pub struct Column<'v, 'i, I>
+where
+    I: 'i + Clone + Hash,
+{
+    /// Single column of DataFrame, dynamically typed Series backed by Array.
+    /// I: type of indexer
+    pub values: Cow<'v, Array>,
+    pub index: Cow<'i, Indexer<I>>,
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Indexing
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, I> RowIndex<'i> for Column<'v, 'i, I>
+where
+    I: Clone + Eq + Hash,
+{
+    type Key = I;
+    type Row = Scalar;
+
+    fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    fn loc(&self, label: &Self::Key) -> Self::Row {
+        let loc = self.index.get_loc(label);
+        self.iloc(&loc)
+    }
+
+    fn try_loc(&self, label: &Self::Key) -> Result<Self::Row, Error> {
+        let loc = self.index.try_get_loc(label)?;
+        Ok(self.iloc(&loc))
+    }
+
+    fn iloc(&self, location: &usize) -> Self::Row {
+        self.values.iloc(location)
+    }
+
+    fn reindex(&self, labels: &[Self::Key]) -> Self {
+        let locations = self.index.get_locs(labels);
+
+        let new_index = self.index.reindex(&locations);
+        let new_values = unsafe { self.values.ilocs_unchecked(&locations) };
+        Column::from_cow(Cow::Owned(new_values), Cow::Owned(new_index))
+    }
+
+    fn reindex_by_index(&self, locations: &[usize]) -> Self {
+        let new_index = self.index.reindex(locations);
+        // boudaries are checked in Indexer.reindex
+        let new_values = unsafe { self.values.ilocs_unchecked(locations) };
+        Column::from_cow(Cow::Owned(new_values), Cow::Owned(new_index))
+    }
+
+    fn blocs(&self, flags: &[bool]) -> Self {
+        let new_values = self.values.blocs(flags);
+        let new_index = self.index.blocs(flags);
+        Column::from_cow(Cow::Owned(new_values), Cow::Owned(new_index))
+    }
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Misc
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, I> Column<'v, 'i, I>
+where
+    I: Clone + Eq + Hash,
+{
+    pub fn new<X>(values: Array, index: X) -> Self
+    where
+        X: Into<Indexer<I>>,
+    {
+        let index: Indexer<I> = index.into();
+
+        assert!(values.len() == index.len(), "Length mismatch!");
+
+        Column {
+            values: Cow::Owned(values),
+            index: Cow::Owned(index),
+        }
+    }
+
+    pub fn from_cow(values: Cow<'v, Array>, index: Cow<'i, Indexer<I>>) -> Self {
+
+        assert!(values.len() == index.len(), "Length mismatch!");
+
+        Column {
+            values,
+            index,
+        }
+    }
+
+    pub fn dtype(&self) -> String {
+        self.values.dtype()
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        self.values.is_numeric()
+    }
+
+    /// Convert to typed Series keeping nulls, Err if dtype is different
+    pub fn as_series<V>(&self) -> Result<Series<'i, 'i, V, I>, Error>
+    where
+        V: 'i + ArrayElement,
+    {
+        let (new_values, mask) = V::from_nullable_array(&self.values)?;
+        // keep borrowing the original index if possible
+        let new_index: Cow<'i, Indexer<I>> = match self.index {
+            Cow::Borrowed(index) => Cow::Borrowed(index),
+            Cow::Owned(ref index) => Cow::Owned(index.clone()),
+        };
+        let mut series = Series::from_cow(Cow::Owned(new_values), new_index);
+        series.mask = mask;
+        Ok(series)
+    }
+
+    /// Encode values to integer codes, returns codes and unique values.
+    /// Codes are assigned in appearance order, or sorted order if sort is true.
+    /// Null is encoded as USIZE_MISSING and is not included in uniques
+    pub fn factorize(&self, sort: bool) -> (Vec<usize>, Array) {
+        Factorize::factorize_array(&self.values, sort)
+    }
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Conversion
+// /////////////////////////////////////////////////////////////////////////////
+
+macro_rules! impl_array_element {
+    ($t:ident, $klass:ident) => {
+        impl ArrayElement for $t {
+            fn dtype() -> String {
+                stringify!($t).to_string()
+            }
+
+            fn from_array(values: &Array) -> Result<Vec<$t>, Error> {
+                match *values {
+                    Array::$klass(ref vals) => {
+                        if vals.has_null() {
+                            Err(Error::InvalidOperation(format!("unable to convert Null to {}",
+                                                            stringify!($t))))
+                        } else {
+                            Ok(vals.clone().into())
+                        }
+                    }
+                    _ => Err(Error::DtypeMismatch(stringify!($t).to_string(), values.dtype())),
+                }
+            }
+
+            fn from_nullable_array(values: &Array) -> Result<(Vec<$t>, Option<Vec<bool>>), Error> {
+                match *values {
+                    Array::$klass(ref vals) => Ok(split_nullvec(vals)),
+                    _ => Err(Error::DtypeMismatch(stringify!($t).to_string(), values.dtype())),
+                }
+            }
+        }
+    }
+}
+impl_array_element!(i64, Int64Array);
+impl_array_element!(i32, Int32Array);
+impl_array_element!(i16, Int16Array);
+impl_array_element!(i8, Int8Array);
+impl_array_element!(isize, IsizeArray);
+impl_array_element!(u64, UInt64Array);
+impl_array_element!(u32, UInt32Array);
+impl_array_element!(u16, UInt16Array);
+impl_array_element!(u8, UInt8Array);
+impl_array_element!(usize, UsizeArray);
+impl_array_element!(bool, BoolArray);
+
+impl ArrayElement for String {
+    fn dtype() -> String {
+        "str".to_string()
+    }
+
+    fn from_array(values: &Array) -> Result<Vec<String>, Error> {
+        match *values {
+            Array::StringArray(ref vals) => {
+                if vals.has_null() {
+                    Err(Error::InvalidOperation("unable to convert Null to String".to_string()))
+                } else {
+                    Ok(vals.clone().into())
+                }
+            }
+            _ => Err(Error::DtypeMismatch("str".to_string(), values.dtype())),
+        }
+    }
+
+    fn from_nullable_array(values: &Array) -> Result<(Vec<String>, Option<Vec<bool>>), Error> {
+        match *values {
+            Array::StringArray(ref vals) => Ok(split_nullvec(vals)),
+            _ => Err(Error::DtypeMismatch("str".to_string(), values.dtype())),
+        }
+    }
+}
+
+/// Split NullVec to raw values and null mask
+fn split_nullvec<T>(values: &NullVec<T>) -> (Vec<T>, Option<Vec<bool>>)
+where
+    T: Clone + NullStorable,
+{
+    let new_values: Vec<T> = values.iter_raw().map(|(_, v)| v.clone()).collect();
+    if values.has_null() {
+        (new_values, Some(values.is_null()))
+    } else {
+        (new_values, None)
+    }
+}
+
+// Null in float Array is converted to NaN
+macro_rules! impl_float_array_element {
+    ($t:ident, $klass:ident) => {
+        impl ArrayElement for $t {
+            fn dtype() -> String {
+                stringify!($t).to_string()
+            }
+
+            fn from_array(values: &Array) -> Result<Vec<$t>, Error> {
+                match *values {
+                    Array::$klass(ref vals) => {
+                        let nvalues: Vec<Nullable<$t>> = vals.clone().into();
+                        Ok(nvalues.into_iter().map(|x| x.into()).collect())
+                    }
+                    _ => Err(Error::DtypeMismatch(stringify!($t).to_string(), values.dtype())),
+                }
+            }
+
+            fn from_nullable_array(values: &Array) -> Result<(Vec<$t>, Option<Vec<bool>>), Error> {
+                match *values {
+                    Array::$klass(ref vals) => {
+                        let mask = split_nullvec(vals).1;
+                        Ok(($t::from_array(values)?, mask))
+                    }
+                    _ => Err(Error::DtypeMismatch(stringify!($t).to_string(), values.dtype())),
+                }
+            }
+        }
+    }
+}
+impl_float_array_element!(f64, Float64Array);
+impl_float_array_element!(f32, Float32Array);
+
+// /////////////////////////////////////////////////////////////////////////////
+// Eq
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, I> PartialEq for Column<'v, 'i, I>
+where
+    I: Clone + Hash + Eq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        (self.index.eq(&other.index)) && (self.values.eq(&other.values))
+    }
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Formatting
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, I> fmt::Display for Column<'v, 'i, I>
+where
+    I: Clone + Eq + Hash,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Column({:?})", &self.values)
+    }
+}
+
+impl<'v, 'i, I> fmt::Debug for Column<'v, 'i, I>
+where
+    I: Clone + Eq + Hash + ToString,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let str_index = formatting::pad_string_vector(&self.index.values);
+        let str_values = formatting::pad_string_vector(&self.values.into_string_vec());
+
+        let mut result = vec![];
+        for (i, v) in str_index.into_iter().zip(str_values) {
+            let row = [i, v];
+            result.push(row.join(" "));
+        }
+        // debug expression {:?} outputs linesep as character, do not use
+        write!(f, "{:}", &result.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::{Array, NullVec};
+
+    use super::Column;
+    use error::Error;
+    use traits::ArrayElement;
+
+    #[test]
+    fn test_array_element_int() {
+        let arr = Array::new(vec![1i64, 2, 3]);
+        assert_eq!(i64::from_array(&arr), Ok(vec![1i64, 2, 3]));
+        assert!(f64::from_array(&arr).is_err());
+        assert!(i32::from_array(&arr).is_err());
+
+        let arr = Array::Int64Array(NullVec::with_mask(vec![1, 2, 3], Some(vec![false, true, false])));
+        let exp = Error::InvalidOperation("unable to convert Null to i64".to_string());
+        assert_eq!(i64::from_array(&arr), Err(exp));
+    }
+
+    #[test]
+    fn test_array_element_nullable() {
+        let arr = Array::Int64Array(NullVec::with_mask(vec![1, 2, 3], Some(vec![false, true, false])));
+        let (values, mask) = i64::from_nullable_array(&arr).unwrap();
+        assert_eq!(values[0], 1);
+        assert_eq!(values[2], 3);
+        assert_eq!(mask, Some(vec![false, true, false]));
+
+        let arr = Array::new(vec!["a".to_string(), "b".to_string()]);
+        let res = String::from_nullable_array(&arr);
+        assert_eq!(res, Ok((vec!["a".to_string(), "b".to_string()], None)));
+        let exp = Error::DtypeMismatch("f64".to_string(), "str".to_string());
+        assert_eq!(f64::from_nullable_array(&arr).err(), Some(exp));
+    }
+
+    #[test]
+    fn test_array_element_float_null() {
+        let arr = Array::Float64Array(NullVec::with_mask(vec![1., 2., 3.],
+                                                         Some(vec![false, true, false])));
+        let res = f64::from_array(&arr).unwrap();
+        assert_eq!(res[0], 1.);
+        assert!(res[1].is_nan());
+        assert_eq!(res[2], 3.);
+    }
+
+    #[test]
+    fn test_column_dtype() {
+        let c = Column::new(Array::new(vec![1.5f64, 2.5]), vec!["A", "B"]);
+        assert_eq!(c.dtype(), f64::dtype());
+        assert!(c.is_numeric());
+    }
+}
@@ -0,0 +1,40 @@
+use std::borrow::{Borrow, Cow};
+use std::hash::Hash;
+
+use super::DataFrame;
+use algos::duplicates::Duplicates;
+use series::Series;
+use traits::{Slicer, IndexerIndex, RowIndex};
+
+//**********************************************
+//*Duplicates
+//**********************************************
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Return whether each row is duplicated or not, comparing values in
+    /// given columns (all columns if None)
+    pub fn duplicated(&self, subset: Option<&[C]>, keep: Duplicates) -> Series<'_, '_, bool, I> {
+        let locations: Vec<usize> = match subset {
+            Some(labels) => self.columns.get_locs(labels),
+            None => (0..self.columns.len()).collect(),
+        };
+        let keys = self.hashable_keys(&locations);
+        let new_values = Duplicates::duplicated(&keys, keep);
+        Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(self.index.borrow()))
+    }
+
+    /// Return DataFrame without duplicated rows, comparing values in
+    /// given columns (all columns if None)
+    pub fn drop_duplicates(&'c self, subset: Option<&[C]>, keep: Duplicates) -> Self {
+        let flags: Vec<bool> = self.duplicated(subset, keep)
+            .values
+            .iter()
+            .map(|x| !x)
+            .collect();
+        self.blocs(&flags)
+    }
+}
@@ -0,0 +1,262 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Scalar, Nullable, NullVec};
+use nullvec::prelude::BasicAggregation as NBasicAggregation;
+use nullvec::prelude::NumericAggregation as NNumericAggregation;
+use nullvec::prelude::ComparisonAggregation as NComparisonAggregation;
+
+use super::DataFrame;
+use error::Error;
+use groupby::{GroupBy, AggFunc, scalars_to_array};
+use indexer::Indexer;
+use traits::{Slicer, IndexerIndex, BasicAggregation, NumericAggregation, ComparisonAggregation, Description};
+
+// /////////////////////////////////////////////////////////////////////////////
+// Misc
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, 'c, I, C, G> GroupBy<'i, DataFrame<'v, 'i, 'c, I, C>, G>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+    G: 'i + Clone + Eq + Hash,
+{
+    /// Apply passed function to each numeric column of each group.
+    /// Non-numeric columns are skipped. Result is indexed by sorted groups.
+    fn aggregate(&'i self, func: &dyn Fn(&Array) -> Scalar, dtype: Option<&Array>) -> DataFrame<'i, 'i, 'i, G, C> {
+        let ndf = self.data.get_numeric_data();
+        let groups = self.groups();
+        let locations = self.locations();
+
+        let mut new_values: Vec<Cow<Array>> = Vec::with_capacity(ndf.values.len());
+        for values in ndf.values.iter() {
+            let aggregated: Vec<Scalar> = locations
+                .iter()
+                .map(|locs| func(&values.ilocs(locs)))
+                .collect();
+            let new_value = scalars_to_array(aggregated, dtype.unwrap_or(values));
+            new_values.push(Cow::Owned(new_value));
+        }
+        DataFrame::from_cow(new_values, Cow::Owned(Indexer::new(groups)), ndf.columns)
+    }
+
+    /// Aggregate each column with the list of functions specified for it.
+    /// Result columns are labeled by pairs of the column and the function name.
+    /// Panics if try_agg returns Err
+    pub fn agg<'f>(&'i self, spec: &[(C, Vec<AggFunc<'f>>)]) -> DataFrame<'i, 'i, 'i, G, (C, &'f str)> {
+        match self.try_agg(spec) {
+            Ok(result) => result,
+            Err(message) => panic!("{}", message),
+        }
+    }
+
+    /// Aggregate each column with the list of functions specified for it.
+    /// Err if a column doesn't exist or a function is not applicable to the column
+    pub fn try_agg<'f>(
+        &'i self,
+        spec: &[(C, Vec<AggFunc<'f>>)],
+    ) -> Result<DataFrame<'i, 'i, 'i, G, (C, &'f str)>, Error> {
+        let groups = self.groups();
+        let locations = self.locations();
+
+        let mut new_values: Vec<Cow<Array>> = vec![];
+        let mut new_columns: Vec<(C, &str)> = vec![];
+        for (column, funcs) in spec.iter() {
+            let values = &self.data.values[self.data.columns.try_get_loc(column)?];
+            for func in funcs.iter() {
+                new_values.push(Cow::Owned(func.aggregate(values, &locations)?));
+                new_columns.push((column.clone(), func.name()));
+            }
+        }
+        Ok(DataFrame::from_cow(
+            new_values,
+            Cow::Owned(Indexer::new(groups)),
+            Cow::Owned(Indexer::new(new_columns)),
+        ))
+    }
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Transform
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, 'c, I, C, G> GroupBy<'i, DataFrame<'v, 'i, 'c, I, C>, G>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+    G: 'i + Clone + Eq + Hash,
+{
+    /// Apply passed function to each numeric column of each group, and return
+    /// the result which has the same length and index as the original DataFrame.
+    /// Non-numeric columns are skipped.
+    pub fn transform(&'i self, func: &dyn Fn(&Array) -> Array) -> DataFrame<'i, 'i, 'i, I, C> {
+        let ndf = self.data.get_numeric_data();
+
+        let mut new_values: Vec<Cow<Array>> = Vec::with_capacity(ndf.values.len());
+        for values in ndf.values.iter() {
+            let mut transformed: Vec<Scalar> = vec![Scalar::Null; values.len()];
+            let mut like: Array = values.ilocs(&[]);
+            for locs in self.locations() {
+                let res = func(&values.ilocs(locs));
+                assert!(res.len() == locs.len(), "Length mismatch!");
+                like = res.ilocs(&[]);
+                let res: Vec<Scalar> = res.into();
+                for (&loc, value) in locs.iter().zip(res) {
+                    transformed[loc] = value;
+                }
+            }
+            new_values.push(Cow::Owned(scalars_to_array(transformed, &like)));
+        }
+        DataFrame::from_cow(new_values, ndf.index, ndf.columns)
+    }
+
+    /// Return rows of groups which satisfy passed predicate, keeping the original order
+    pub fn filter(&self, func: &dyn Fn(&DataFrame<'v, 'i, 'c, I, C>) -> bool) -> DataFrame<'v, 'i, 'c, I, C> {
+        let mut locations: Vec<usize> = vec![];
+        for locs in self.locations() {
+            if func(&self.take(locs)) {
+                locations.extend(locs);
+            }
+        }
+        locations.sort();
+        self.take(&locations)
+    }
+
+    fn take(&self, locations: &[usize]) -> DataFrame<'v, 'i, 'c, I, C> {
+        let new_values: Vec<Cow<Array>> = self.data
+            .values
+            .iter()
+            .map(|x| Cow::Owned(x.ilocs(locations)))
+            .collect();
+        DataFrame::from_cow(
+            new_values,
+            Cow::Owned(self.data.index.reindex(locations)),
+            self.data.columns.clone(),
+        )
+    }
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Aggregation
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, 'c, I, C, G> BasicAggregation<'i> for GroupBy<'i, DataFrame<'v, 'i, 'c, I, C>, G>
+where
+    I: Clone + Eq + Hash,
+    C: 'i + Clone + Eq + Hash,
+    G: 'i + Clone + Eq + Hash,
+{
+    // ToDo: use 'n lifetime for values
+    type Kept = DataFrame<'i, 'i, 'i, G, C>;
+    type Counted = DataFrame<'i, 'i, 'i, G, C>;
+
+    fn sum(&'i self) -> Self::Kept {
+        self.aggregate(&|x: &Array| x.sum(), None)
+    }
+
+    fn count(&'i self) -> Self::Counted {
+        self.aggregate(&|x: &Array| Scalar::usize(x.count()), None)
+    }
+}
+
+impl<'v, 'i, 'c, I, C, G> NumericAggregation<'i> for GroupBy<'i, DataFrame<'v, 'i, 'c, I, C>, G>
+where
+    I: Clone + Eq + Hash,
+    C: 'i + Clone + Eq + Hash,
+    G: 'i + Clone + Eq + Hash,
+{
+    // ToDo: use 'n lifetime for values
+    type Coerced = DataFrame<'i, 'i, 'i, G, C>;
+
+    fn mean(&'i self) -> Self::Coerced {
+        let dtype = Array::new(Vec::<f64>::new());
+        self.aggregate(&|x: &Array| x.mean().into(), Some(&dtype))
+    }
+
+    fn var(&'i self) -> Self::Coerced {
+        let dtype = Array::new(Vec::<f64>::new());
+        self.aggregate(&|x: &Array| x.var().into(), Some(&dtype))
+    }
+
+    fn unbiased_var(&'i self) -> Self::Coerced {
+        let dtype = Array::new(Vec::<f64>::new());
+        self.aggregate(&|x: &Array| x.unbiased_var().into(), Some(&dtype))
+    }
+
+    fn std(&'i self) -> Self::Coerced {
+        let dtype = Array::new(Vec::<f64>::new());
+        self.aggregate(&|x: &Array| x.std().into(), Some(&dtype))
+    }
+
+    fn unbiased_std(&'i self) -> Self::Coerced {
+        let dtype = Array::new(Vec::<f64>::new());
+        self.aggregate(&|x: &Array| x.unbiased_std().into(), Some(&dtype))
+    }
+}
+
+impl<'v, 'i, 'c, I, C, G> ComparisonAggregation<'i>
+    for GroupBy<'i, DataFrame<'v, 'i, 'c, I, C>, G>
+where
+    I: Clone + Eq + Hash,
+    C: 'i + Clone + Eq + Hash,
+    G: 'i + Clone + Eq + Hash,
+{
+    // ToDo: use 'n lifetime for values
+    type Kept = DataFrame<'i, 'i, 'i, G, C>;
+
+    fn min(&'i self) -> Self::Kept {
+        self.aggregate(&|x: &Array| x.min(), None)
+    }
+
+    fn max(&'i self) -> Self::Kept {
+        self.aggregate(&|x: &Array| x.max(), None)
+    }
+}
+
+impl<'v, 'i, 'c, I, C, G> Description<'i> for GroupBy<'i, DataFrame<'v, 'i, 'c, I, C>, G>
+where
+    I: Clone + Eq + Hash,
+    C: 'i + Clone + Eq + Hash,
+    G: 'i + Clone + Eq + Hash,
+{
+    /// Columns are pairs of the original column and the statistic
+    type Described = DataFrame<'i, 'i, 'i, G, (C, &'i str)>;
+
+    fn describe(&'i self) -> Self::Described {
+        let ndf = self.data.get_numeric_data();
+        let groups = self.groups();
+        let locations = self.locations();
+
+        let stats: Vec<&str> = vec!["count", "mean", "std", "min", "max"];
+
+        let describe = |x: &Array| {
+            vec![
+                Nullable::new(x.count() as f64),
+                x.mean(),
+                x.std(),
+                x.min().as_f64(),
+                x.max().as_f64(),
+            ]
+        };
+
+        let mut new_values: Vec<Cow<Array>> = Vec::with_capacity(ndf.values.len() * stats.len());
+        let mut new_columns: Vec<(C, &str)> = Vec::with_capacity(ndf.values.len() * stats.len());
+        for (values, column) in ndf.values.iter().zip(ndf.columns.values.iter()) {
+            let described: Vec<Vec<Nullable<f64>>> = locations
+                .iter()
+                .map(|locs| describe(&values.ilocs(locs)))
+                .collect();
+            for (i, stat) in stats.iter().enumerate() {
+                let new_value: NullVec<f64> = described.iter().map(|x| x[i].clone()).collect();
+                new_values.push(Cow::Owned(Array::Float64Array(new_value)));
+                new_columns.push((column.clone(), stat));
+            }
+        }
+        DataFrame::from_cow(
+            new_values,
+            Cow::Owned(Indexer::new(groups)),
+            Cow::Owned(Indexer::new(new_columns)),
+        )
+    }
+}
@@ -0,0 +1,181 @@
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::hash::Hash;
+
+use nullvec::prelude::Array;
+use nullvec::prelude::Append as NullVecAppend;
+
+use super::DataFrame;
+use algos::join::{MergeOp, HashJoin, USIZE_MISSING};
+use error::Error;
+use indexer::Indexer;
+use traits::{Slicer, IndexerIndex};
+
+/// How to merge DataFrames
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JoinHow {
+    /// use keys which appear in both
+    Inner,
+    /// use keys in left, keep left order
+    Left,
+    /// use keys in right, keep right order
+    Right,
+    /// use keys in either, keys only in right are appended to the last
+    Outer,
+}
+
+/// Relationship of merge keys to be checked
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MergeValidate {
+    /// keys must be unique in both left and right
+    OneToOne,
+    /// keys must be unique in left
+    OneToMany,
+    /// keys must be unique in right
+    ManyToOne,
+}
+
+impl MergeValidate {
+    fn validate<T: Hash + Eq>(&self, left: &[T], right: &[T]) -> Result<(), Error> {
+        let (check_left, check_right) = match *self {
+            MergeValidate::OneToOne => (true, true),
+            MergeValidate::OneToMany => (true, false),
+            MergeValidate::ManyToOne => (false, true),
+        };
+        if check_left && !is_unique(left) {
+            return Err(Error::InvalidOperation("merge keys are not unique in left".to_string()));
+        }
+        if check_right && !is_unique(right) {
+            return Err(Error::InvalidOperation("merge keys are not unique in right".to_string()));
+        }
+        Ok(())
+    }
+}
+
+fn is_unique<T: Hash + Eq>(values: &[T]) -> bool {
+    let mut set: HashSet<&T> = HashSet::with_capacity(values.len());
+    values.iter().all(|x| set.insert(x))
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash + ToString,
+{
+    /// Merge with other DataFrame using given columns as keys (SQL-style join).
+    /// Result has a new RangeIndex, and non-key columns which exist in both
+    /// are suffixed with "_x" and "_y". Panics if merge_with returns Err
+    pub fn merge(&self, other: &Self, on: &[C], how: JoinHow) -> DataFrame<'v, 'i, 'c, usize, String> {
+        match self.merge_with(other, on, how, ("_x", "_y"), None) {
+            Ok(merged) => merged,
+            Err(message) => panic!("{}", message),
+        }
+    }
+
+    /// Merge with specified suffixes for overlapping columns. Err if no key is
+    /// given, a key is given more than once, a key column doesn't exist in either
+    /// side, key columns have different dtypes, or merge keys don't satisfy validate
+    pub fn merge_with(
+        &self,
+        other: &Self,
+        on: &[C],
+        how: JoinHow,
+        suffixes: (&str, &str),
+        validate: Option<MergeValidate>,
+    ) -> Result<DataFrame<'v, 'i, 'c, usize, String>, Error> {
+        if on.is_empty() {
+            let message = "at least one key column is required".to_string();
+            return Err(Error::InvalidOperation(message));
+        }
+        if !is_unique(on) {
+            return Err(Error::DuplicatedLabel);
+        }
+        let llocs = key_locs(&self.columns, on)?;
+        let rlocs = key_locs(&other.columns, on)?;
+        for (&l, &r) in llocs.iter().zip(&rlocs) {
+            let (ldtype, rdtype) = (self.values[l].dtype(), other.values[r].dtype());
+            if ldtype != rdtype {
+                return Err(Error::DtypeMismatch(ldtype, rdtype));
+            }
+        }
+
+        let lkeys = self.hashable_keys(&llocs);
+        let rkeys = other.hashable_keys(&rlocs);
+
+        if let Some(validate) = validate {
+            validate.validate(&lkeys, &rkeys)?;
+        }
+
+        let (lindexer, rindexer) = match how {
+            JoinHow::Inner => HashJoin::merge_inner(&lkeys, &rkeys),
+            JoinHow::Left => HashJoin::merge_left(&lkeys, &rkeys),
+            JoinHow::Right => HashJoin::merge_right(&lkeys, &rkeys),
+            JoinHow::Outer => HashJoin::merge_outer(&lkeys, &rkeys),
+        };
+
+        let capacity = self.columns.len() + other.columns.len() - on.len();
+        let mut new_values: Vec<Cow<Array>> = Vec::with_capacity(capacity);
+        let mut new_columns: Vec<String> = Vec::with_capacity(capacity);
+
+        for (values, name) in self.values.iter().zip(&self.columns.values) {
+            match on.iter().position(|x| x == name) {
+                Some(k) => {
+                    let rvalues = &other.values[rlocs[k]];
+                    let new_value = merge_key_values(values, rvalues, &lindexer, &rindexer);
+                    new_values.push(Cow::Owned(new_value));
+                    new_columns.push(name.to_string());
+                }
+                None => {
+                    new_values.push(Cow::Owned(values.ilocs_forced(&lindexer)));
+                    if other.columns.contains(name) {
+                        new_columns.push(name.to_string() + suffixes.0);
+                    } else {
+                        new_columns.push(name.to_string());
+                    }
+                }
+            }
+        }
+        for (values, name) in other.values.iter().zip(&other.columns.values) {
+            if on.contains(name) {
+                continue;
+            }
+            new_values.push(Cow::Owned(values.ilocs_forced(&rindexer)));
+            if self.columns.contains(name) {
+                new_columns.push(name.to_string() + suffixes.1);
+            } else {
+                new_columns.push(name.to_string());
+            }
+        }
+
+        let new_index: Indexer<usize> = Indexer::<usize>::from_len(lindexer.len());
+        Ok(DataFrame::from_cow(
+            new_values,
+            Cow::Owned(new_index),
+            Cow::Owned(Indexer::new(new_columns)),
+        ))
+    }
+}
+
+/// Locations of key columns, Err if any of them doesn't exist
+fn key_locs<C>(columns: &Indexer<C>, on: &[C]) -> Result<Vec<usize>, Error>
+where
+    C: Clone + Eq + Hash,
+{
+    on.iter().map(|c| columns.try_get_loc(c)).collect()
+}
+
+/// Take key values from left, or from right if the row doesn't exist in left.
+/// Both must have the same dtype
+fn merge_key_values(left: &Array, right: &Array, lindexer: &[usize], rindexer: &[usize]) -> Array {
+    if !lindexer.contains(&USIZE_MISSING) {
+        return left.ilocs(lindexer);
+    }
+
+    let offset = left.len();
+    let locations: Vec<usize> = lindexer
+        .iter()
+        .zip(rindexer)
+        .map(|(&l, &r)| if l == USIZE_MISSING { offset + r } else { l })
+        .collect();
+    left.append(right).ilocs(&locations)
+}
@@ -0,0 +1,200 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, Scalar};
+use nullvec::prelude::Append as NullVecAppend;
+
+use super::DataFrame;
+use algos::fill::{FillNA, Propagate, Interpolation};
+use algos::join::USIZE_MISSING;
+use algos::window::Window;
+use error::Error;
+use traits::{Slicer, IndexerIndex};
+
+/// Axis to drop labels from
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Axis {
+    /// drop rows
+    Index,
+    /// drop columns
+    Columns,
+}
+
+/// Condition to drop labels
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DropHow {
+    /// drop if any value is null
+    Any,
+    /// drop if all values are null
+    All,
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Missing values
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Drop rows or columns which contain nulls. If thresh is specified, labels which
+    /// have at least thresh non-null values are kept regardless of how.
+    /// subset limits columns to be checked, and is only supported for Axis::Index.
+    pub fn dropna(
+        &self,
+        axis: Axis,
+        how: DropHow,
+        thresh: Option<usize>,
+        subset: Option<&[C]>,
+    ) -> Self {
+        let keep = |nulls: usize, len: usize| match (thresh, how) {
+            (Some(thresh), _) => len - nulls >= thresh,
+            (None, DropHow::Any) => nulls == 0,
+            (None, DropHow::All) => nulls < len,
+        };
+
+        match axis {
+            Axis::Index => {
+                let locations = match subset {
+                    Some(labels) => self.columns.get_locs(labels),
+                    None => (0..self.values.len()).collect(),
+                };
+                let masks: Vec<Vec<bool>> =
+                    locations.iter().map(|&loc| null_mask(&self.values[loc])).collect();
+                let indexer: Vec<usize> = (0..self.index.len())
+                    .filter(|&i| {
+                        let nulls = masks.iter().filter(|mask| mask[i]).count();
+                        keep(nulls, masks.len())
+                    })
+                    .collect();
+                // ilocs_forced drops the null mask if the result has no null
+                let new_values: Vec<Cow<Array>> = self.values
+                    .iter()
+                    .map(|x| Cow::Owned(x.ilocs_forced(&indexer)))
+                    .collect();
+                DataFrame::from_cow(
+                    new_values,
+                    Cow::Owned(self.index.reindex(&indexer)),
+                    self.columns.clone(),
+                )
+            }
+            Axis::Columns => {
+                assert!(subset.is_none(), "subset is only supported for Axis::Index");
+                let indexer: Vec<usize> = (0..self.values.len())
+                    .filter(|&loc| {
+                        let nulls = null_mask(&self.values[loc]).iter().filter(|&&m| m).count();
+                        keep(nulls, self.index.len())
+                    })
+                    .collect();
+                let new_values: Vec<Cow<Array>> =
+                    indexer.iter().map(|&loc| self.values[loc].clone()).collect();
+                DataFrame::from_cow(
+                    new_values,
+                    self.index.clone(),
+                    Cow::Owned(self.columns.reindex(&indexer)),
+                )
+            }
+        }
+    }
+
+    /// Fill nulls with the value specified per column, or by propagating valid values
+    /// in each column. Columns which are not specified by Value are kept as they are.
+    /// If limit is specified, at most limit consecutive nulls are filled by propagation,
+    /// or at most limit nulls are filled in each column by Value.
+    /// Panics if try_fillna returns Err
+    pub fn fillna(&self, method: FillNA<&[(C, Scalar)]>, limit: Option<usize>) -> Self {
+        match self.try_fillna(method, limit) {
+            Ok(filled) => filled,
+            Err(message) => panic!("{}", message),
+        }
+    }
+
+    /// Fill nulls, Err if a column specified by Value doesn't exist, or the value
+    /// is Null or has a different dtype from the column
+    pub fn try_fillna(
+        &self,
+        method: FillNA<&[(C, Scalar)]>,
+        limit: Option<usize>,
+    ) -> Result<Self, Error> {
+        let limit = limit.unwrap_or(usize::MAX);
+        let new_values: Vec<Cow<Array>> = match method {
+            FillNA::Value(spec) => {
+                let mut new_values = self.values.clone();
+                for (column, value) in spec.iter() {
+                    let loc = self.columns.try_get_loc(column)?;
+                    new_values[loc] = Cow::Owned(fill_value(&self.values[loc], value, limit)?);
+                }
+                new_values
+            }
+            FillNA::Ffill => {
+                self.values
+                    .iter()
+                    .map(|x| {
+                        let indexer = Propagate::forward(&null_mask(x), Some(limit));
+                        Cow::Owned(x.ilocs_forced(&indexer))
+                    })
+                    .collect()
+            }
+            FillNA::Bfill => {
+                self.values
+                    .iter()
+                    .map(|x| {
+                        let indexer = Propagate::backward(&null_mask(x), Some(limit));
+                        Cow::Owned(x.ilocs_forced(&indexer))
+                    })
+                    .collect()
+            }
+        };
+        Ok(DataFrame::from_cow(new_values, self.index.clone(), self.columns.clone()))
+    }
+
+    /// Interpolate nulls in numeric columns, which are converted to f64.
+    /// Leading and trailing nulls are kept, and non-numeric columns are kept as they are.
+    pub fn interpolate(&self, method: Interpolation) -> Self {
+        let new_values: Vec<Cow<Array>> = self.values
+            .iter()
+            .map(|x| if x.is_numeric() {
+                Cow::Owned(Array::Float64Array(method.interpolate(&Window::from_array(x))))
+            } else {
+                x.clone()
+            })
+            .collect();
+        DataFrame::from_cow(new_values, self.index.clone(), self.columns.clone())
+    }
+}
+
+/// Whether each value is null
+fn null_mask(values: &Array) -> Vec<bool> {
+    (0..values.len())
+        .map(|i| values.iloc(&i) == Scalar::Null)
+        .collect()
+}
+
+/// Fill at most limit nulls with value, Err if value is Null or has a different
+/// dtype from values
+fn fill_value(values: &Array, value: &Scalar, limit: usize) -> Result<Array, Error> {
+    if *value == Scalar::Null {
+        return Err(Error::InvalidOperation("fill value must not be Null".to_string()));
+    }
+    let filler = Array::from(vec![value.clone()]);
+    if filler.dtype() != values.dtype() {
+        return Err(Error::DtypeMismatch(values.dtype(), filler.dtype()));
+    }
+
+    let mut filled: usize = 0;
+    let indexer: Vec<usize> = null_mask(values)
+        .iter()
+        .enumerate()
+        .map(|(i, &is_null)| if !is_null {
+            i
+        } else if filled < limit {
+            filled += 1;
+            // location of filler
+            values.len()
+        } else {
+            USIZE_MISSING
+        })
+        .collect();
+    Ok(values.append(&filler).ilocs_forced(&indexer))
+}
@@ -3,15 +3,34 @@ use std::hash::Hash;
 use std::slice;
 use std::vec;
 
-use nullvec::prelude::Array;
+use nullvec::prelude::{Array, NullVec, Scalar};
 
-use groupby::GroupBy;
+use algos::hash::{HashableScalar, HashableTuple};
+use error::Error;
+use groupby::{GroupBy, GroupByOptions, scalars_to_array};
 use indexer::Indexer;
-use traits::{Slicer, IndexerIndex, RowIndex, ColIndex};
+use series::Series;
+use traits::{Slicer, IndexerIndex, RowIndex, ColIndex, ArrayElement};
 
 mod aggregation;
+mod column;
+mod duplicates;
 mod formatting;
+mod groupby;
+mod merge;
+mod missing;
+mod ops;
 mod reshape;
+mod rolling;
+mod row;
+mod sort;
+mod transform;
+
+pub use self::column::Column;
+pub use self::merge::{JoinHow, MergeValidate};
+pub use self::missing::{Axis, DropHow};
+pub use self::ops::{FrameOperand, ColumnWise};
+pub use self::row::Row;
 
 #[derive(Clone)]
 pub struct DataFrame<'v, 'i, 'c, I, C>
@@ -27,9 +46,9 @@ where
     pub columns: Cow<'c, Indexer<C>>,
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Indexing
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Indexing
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<'v, 'i, 'c, I, C> RowIndex<'c> for DataFrame<'v, 'i, 'c, I, C>
 where
@@ -37,18 +56,25 @@ where
     C: Clone + Eq + Hash,
 {
     type Key = I;
-    type Row = Array;
+    type Row = Row<'c, C>;
 
     fn len(&'c self) -> usize {
         self.index.len()
     }
 
-    fn loc(&'c self, _label: &Self::Key) -> Self::Row {
-        unimplemented!()
+    fn loc(&'c self, label: &Self::Key) -> Self::Row {
+        let loc = self.index.get_loc(label);
+        self.iloc(&loc)
+    }
+
+    fn try_loc(&'c self, label: &Self::Key) -> Result<Self::Row, Error> {
+        let loc = self.index.try_get_loc(label)?;
+        Ok(self.iloc(&loc))
     }
 
-    fn iloc(&'c self, _locaiton: &usize) -> Self::Row {
-        unimplemented!()
+    fn iloc(&'c self, location: &usize) -> Self::Row {
+        let new_values: Vec<Scalar> = self.values.iter().map(|x| x.iloc(location)).collect();
+        Row::from_cow(new_values, Cow::Borrowed(self.columns.borrow()))
     }
 
     fn reindex<'l>(&'c self, labels: &'l [Self::Key]) -> Self {
@@ -72,9 +98,48 @@ where
         )
     }
 
-    fn blocs(&self, _labels: &[bool]) -> Self {
-        unimplemented!()
-        // ToDo: fix Series impl
+    fn blocs(&'c self, flags: &[bool]) -> Self {
+        let new_index = self.index.blocs(flags);
+
+        let mut new_values: Vec<Cow<Array>> = Vec::with_capacity(self.columns.len());
+        for current in &self.values {
+            new_values.push(Cow::Owned(current.blocs(flags)));
+        }
+        DataFrame::from_cow(
+            new_values,
+            Cow::Owned(new_index),
+            Cow::Borrowed(self.columns.borrow()),
+        )
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Select rows where mask is true. Mask must have the same index as
+    /// the DataFrame, and null in mask is regarded as false.
+    pub fn filter(&self, mask: &Series<bool, I>) -> Self {
+        assert!(self.index == mask.index, "index must be the same!");
+
+        let locations: Vec<usize> = mask.notnull()
+            .iter()
+            .zip(mask.iter())
+            .enumerate()
+            .filter(|&(_, (&valid, &flag))| valid && flag)
+            .map(|(i, _)| i)
+            .collect();
+        // ilocs_forced drops the null mask if the result has no null
+        let new_values: Vec<Cow<Array>> = self.values
+            .iter()
+            .map(|x| Cow::Owned(x.ilocs_forced(&locations)))
+            .collect();
+        DataFrame::from_cow(
+            new_values,
+            Cow::Owned(self.index.reindex(&locations)),
+            self.columns.clone(),
+        )
     }
 }
 
@@ -84,14 +149,18 @@ where
     C: Clone + Eq + Hash,
 {
     type Key = C;
-    type Column = Array;
+    type Column = Column<'i, 'i, I>;
 
-    fn get(&'i self, _label: &Self::Key) -> Self::Column {
-        unimplemented!();
+    fn get(&'i self, label: &Self::Key) -> Self::Column {
+        let loc = self.columns.get_loc(label);
+        self.iget(&loc)
     }
 
-    fn iget(&'i self, _loc: &usize) -> Self::Column {
-        unimplemented!();
+    fn iget(&'i self, loc: &usize) -> Self::Column {
+        Column::from_cow(
+            Cow::Borrowed(self.values[*loc].borrow()),
+            Cow::Borrowed(self.index.borrow()),
+        )
     }
 
     fn gets<'l>(&'i self, labels: &'l [Self::Key]) -> Self {
@@ -115,9 +184,9 @@ where
     }
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Misc
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Misc
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
 where
@@ -141,12 +210,62 @@ where
             assert!(value.len() == len, "Length mismatch!");
         }
         DataFrame {
-            values: values,
+            values,
             index: Cow::Owned(index),
             columns: Cow::Owned(columns),
         }
     }
 
+    /// Instanciate from Vec of Array, Err if values, index and columns have
+    /// different length
+    pub fn try_from_vec<X, Y>(values: Vec<Array>, index: X, columns: Y) -> Result<Self, Error>
+    where
+        X: Into<Indexer<I>>,
+        Y: Into<Indexer<C>>,
+    {
+        let index: Indexer<I> = index.into();
+        let columns: Indexer<C> = columns.into();
+
+        if values.len() != columns.len() {
+            return Err(Error::LengthMismatch(columns.len(), values.len()));
+        }
+        for value in &values {
+            if value.len() != index.len() {
+                return Err(Error::LengthMismatch(index.len(), value.len()));
+            }
+        }
+        Ok(DataFrame::from_vec(values, index, columns))
+    }
+
+    /// Instanciate from Rows, columns are taken from the first Row
+    pub fn from_rows<X>(rows: Vec<Row<C>>, index: X) -> Self
+    where
+        X: Into<Indexer<I>>,
+    {
+        assert!(!rows.is_empty(), "Unable to infer dtype");
+
+        let columns: Indexer<C> = rows[0].columns.clone().into_owned();
+
+        // column-wise vec of scalar
+        let mut colvecs: Vec<Vec<Scalar>> = Vec::with_capacity(columns.len());
+        for _ in 0..columns.len() {
+            colvecs.push(Vec::with_capacity(rows.len()));
+        }
+
+        for row in rows {
+            assert!(row.columns.as_ref() == &columns, "columns must be identical");
+            for (column, val) in colvecs.iter_mut().zip(row.values) {
+                column.push(val);
+            }
+        }
+        // dtype is inferred from the first non-null value, all-null column is f64
+        let like = Array::Float64Array(NullVec::new(vec![]));
+        let arrays: Vec<Array> = colvecs.into_iter()
+            .map(|x| scalars_to_array(x, &like))
+            .collect();
+        DataFrame::from_vec(arrays, index, columns)
+    }
+
     fn from_cow(
         values: Vec<Cow<'v, Array>>,
         index: Cow<'i, Indexer<I>>,
@@ -154,12 +273,18 @@ where
     ) -> Self {
         // temp internal, use IntoCow
         DataFrame {
-            values: values,
-            index: index,
-            columns: columns,
+            values,
+            index,
+            columns,
         }
     }
 
+    /// Composite keys of each row using given column locations
+    fn hashable_keys(&self, locations: &[usize]) -> Vec<Vec<HashableScalar>> {
+        let values: Vec<&Array> = locations.iter().map(|x| self.values[*x].as_ref()).collect();
+        HashableScalar::from_arrays(&values)
+    }
+
     pub fn dtypes(&self) -> Vec<String> {
         self.iter().map(|x| x.dtype()).collect()
     }
@@ -180,6 +305,30 @@ where
         self.igets(&indexer)
     }
 
+    /// Get column as typed Series keeping nulls, Err if label doesn't exist
+    /// or dtype is different
+    pub fn get_as<V>(&'i self, label: &C) -> Result<Series<'i, 'i, V, I>, Error>
+    where
+        V: 'i + ArrayElement,
+    {
+        let loc = self.columns.try_get_loc(label)?;
+        self.iget_as(&loc)
+    }
+
+    /// Get column as typed Series using given index keeping nulls, Err if dtype is different
+    pub fn iget_as<V>(&'i self, loc: &usize) -> Result<Series<'i, 'i, V, I>, Error>
+    where
+        V: 'i + ArrayElement,
+    {
+        let (new_values, mask) = V::from_nullable_array(&self.values[*loc])?;
+        let mut series = Series::from_cow(
+            Cow::Owned(new_values),
+            Cow::Borrowed(self.index.borrow()),
+        );
+        series.mask = mask;
+        Ok(series)
+    }
+
     pub fn insert(&mut self, values: Array, name: C) {
         assert!(self.len() == values.len(), "Length mismatch!");
 
@@ -187,18 +336,57 @@ where
         self.columns.to_mut().push(name);
     }
 
+    /// Insert column, Err if values have different length
+    pub fn try_insert(&mut self, values: Array, name: C) -> Result<(), Error> {
+        if values.len() != self.index.len() {
+            return Err(Error::LengthMismatch(self.index.len(), values.len()));
+        }
+        self.insert(values, name);
+        Ok(())
+    }
+
     pub fn groupby<G>(&'i self, other: &[G]) -> GroupBy<DataFrame<I, C>, G>
     where
-        G: Clone + Eq + Hash + Ord,
+        G: 'i + Clone + Eq + Hash + Ord,
     {
 
         GroupBy::new(self, other)
     }
+
+    /// Group by values of given columns, each group is labeled by the tuple of values
+    pub fn groupby_columns(
+        &'i self,
+        labels: &[C],
+    ) -> GroupBy<'i, DataFrame<'i, 'i, 'i, I, C>, HashableTuple> {
+        let locations = self.columns.get_locs(labels);
+        let keys: Vec<HashableTuple> = self.hashable_keys(&locations)
+            .into_iter()
+            .map(HashableTuple::new)
+            .collect();
+        GroupBy::new(self, &keys)
+    }
+
+    /// Group by values of given columns with options.
+    /// A key is regarded as null if any of its values is null
+    pub fn groupby_columns_with(
+        &'i self,
+        labels: &[C],
+        options: GroupByOptions,
+    ) -> GroupBy<'i, DataFrame<'i, 'i, 'i, I, C>, HashableTuple> {
+        let locations = self.columns.get_locs(labels);
+        let keys: Vec<HashableTuple> = self.hashable_keys(&locations)
+            .into_iter()
+            .map(HashableTuple::new)
+            .collect();
+        GroupBy::with_options(self, &keys, options, |x: &HashableTuple| {
+            x.values.contains(&HashableScalar::Null)
+        })
+    }
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Eq
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Eq
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<'v, 'i, 'c, I, C> PartialEq for DataFrame<'v, 'i, 'c, I, C>
 where
@@ -211,9 +399,9 @@ where
     }
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Iterator
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Iterator
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<'v, 'i, 'c, I, C> IntoIterator for DataFrame<'v, 'i, 'c, I, C>
 where
@@ -0,0 +1,611 @@
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::hash::Hash;
+use std::ops::{Add, Mul, Sub, Div, Rem, BitAnd, BitOr, BitXor};
+
+use nullvec::prelude::{Array, Nullable, NullVec, Scalar};
+use nullvec::prelude::dev::NullStorable;
+
+use super::DataFrame;
+use algos::join::{JoinOp, HashJoin, USIZE_MISSING};
+use error::Error;
+use indexer::Indexer;
+use series::Series;
+use traits::Slicer;
+
+/// Right hand side of DataFrame operations. Implemented for scalars,
+/// DataFrame, Series aligned with columns (broadcast to each row) and
+/// `ColumnWise` Series aligned with index (broadcast to each column)
+pub trait FrameOperand<I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Align both sides to the index and columns of the result
+    fn align_with(&self, frame: &DataFrame<I, C>) -> Aligned<I, C>;
+}
+
+/// Operands aligned to the result. Column which doesn't exist in the side is None
+pub struct Aligned<I, C>
+where
+    I: Clone + Hash,
+    C: Clone + Hash,
+{
+    index: Indexer<I>,
+    columns: Indexer<C>,
+    left: Vec<Option<Array>>,
+    right: Vec<Option<Array>>,
+    /// whether right is a scalar, integer result keeps the dtype of left
+    scalar: bool,
+}
+
+/// Series broadcast to each column of DataFrame, aligned with its index
+pub struct ColumnWise<'a, S: 'a>(pub &'a S);
+
+// /////////////////////////////////////////////////////////////////////////////
+// Operands
+// /////////////////////////////////////////////////////////////////////////////
+
+macro_rules! define_scalar_operand {
+    ($t:ident) => {
+        impl<I, C> FrameOperand<I, C> for $t
+            where I: Clone + Eq + Hash,
+                  C: Clone + Eq + Hash {
+
+            fn align_with(&self, frame: &DataFrame<I, C>) -> Aligned<I, C> {
+                let value = Array::new(vec![*self; frame.index.len()]);
+                Aligned {
+                    index: frame.index.clone().into_owned(),
+                    columns: frame.columns.clone().into_owned(),
+                    left: frame.values.iter().map(|x| Some(x.clone().into_owned())).collect(),
+                    right: vec![Some(value); frame.columns.len()],
+                    scalar: true,
+                }
+            }
+        }
+    }
+}
+
+define_scalar_operand!(i64);
+define_scalar_operand!(f64);
+define_scalar_operand!(bool);
+
+impl<'a, 'v, 'i, 'c, I, C> FrameOperand<I, C> for &'a DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    fn align_with(&self, frame: &DataFrame<I, C>) -> Aligned<I, C> {
+        let (index, lrows, rrows) = union(&frame.index, &self.index);
+        let (columns, lcols, rcols) = union(&frame.columns, &self.columns);
+        Aligned {
+            index,
+            columns,
+            left: take(frame, &lrows, &lcols),
+            right: take(self, &rrows, &rcols),
+            scalar: false,
+        }
+    }
+}
+
+impl<'a, 'v, 'i, V, I, C> FrameOperand<I, C> for &'a Series<'v, 'i, V, C>
+where
+    V: Clone,
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+    Array: From<Vec<V>>,
+{
+    fn align_with(&self, frame: &DataFrame<I, C>) -> Aligned<I, C> {
+        let (columns, lcols, rcols) = union(&frame.columns, &self.index);
+        let rows: Vec<usize> = (0..frame.index.len()).collect();
+
+        let values = self.to_array();
+        let right = rcols
+            .iter()
+            .map(|&c| if c == USIZE_MISSING {
+                None
+            } else {
+                Some(values.ilocs_forced(&vec![c; rows.len()]))
+            })
+            .collect();
+        Aligned {
+            index: frame.index.clone().into_owned(),
+            columns,
+            left: take(frame, &rows, &lcols),
+            right,
+            scalar: false,
+        }
+    }
+}
+
+impl<'a, 'v, 'i, V, I, C> FrameOperand<I, C> for ColumnWise<'a, Series<'v, 'i, V, I>>
+where
+    V: Clone,
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+    Array: From<Vec<V>>,
+{
+    fn align_with(&self, frame: &DataFrame<I, C>) -> Aligned<I, C> {
+        let (index, lrows, rrows) = union(&frame.index, &self.0.index);
+        let cols: Vec<usize> = (0..frame.columns.len()).collect();
+
+        let values = self.0.to_array().ilocs_forced(&rrows);
+        Aligned {
+            index,
+            columns: frame.columns.clone().into_owned(),
+            left: take(frame, &lrows, &cols),
+            right: vec![Some(values); cols.len()],
+            scalar: false,
+        }
+    }
+}
+
+/// Union of labels and locations of each side. Labels are kept as they are
+/// if both are identical
+fn union<T>(left: &Indexer<T>, right: &Indexer<T>) -> (Indexer<T>, Vec<usize>, Vec<usize>)
+where
+    T: Clone + Eq + Hash,
+{
+    if left == right {
+        let locations: Vec<usize> = (0..left.len()).collect();
+        (left.clone(), locations.clone(), locations)
+    } else {
+        let (labels, lindexer, rindexer) = HashJoin::outer(&left.values, &right.values);
+        (Indexer::new(labels), lindexer, rindexer)
+    }
+}
+
+/// Take columns at given locations, missing rows are null
+fn take<I, C>(frame: &DataFrame<I, C>, rows: &[usize], columns: &[usize]) -> Vec<Option<Array>>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    columns
+        .iter()
+        .map(|&c| if c == USIZE_MISSING {
+            None
+        } else {
+            Some(frame.values[c].ilocs_forced(rows))
+        })
+        .collect()
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Compute
+// /////////////////////////////////////////////////////////////////////////////
+
+#[derive(Clone, Copy)]
+enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    BitAnd,
+    BitOr,
+    BitXor,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+    Eq,
+    Ne,
+}
+
+impl Operator {
+    fn name(&self) -> &'static str {
+        match *self {
+            Operator::Add => "add",
+            Operator::Sub => "sub",
+            Operator::Mul => "mul",
+            Operator::Div => "div",
+            Operator::Rem => "rem",
+            Operator::BitAnd => "bitand",
+            Operator::BitOr => "bitor",
+            Operator::BitXor => "bitxor",
+            Operator::Gt => "gt",
+            Operator::Ge => "ge",
+            Operator::Lt => "lt",
+            Operator::Le => "le",
+            Operator::Eq => "eq",
+            Operator::Ne => "ne",
+        }
+    }
+
+    /// Comparison result, None if the operator is not a comparison
+    fn compare<T: PartialOrd>(&self, x: &T, y: &T) -> Option<bool> {
+        match *self {
+            Operator::Gt => Some(x > y),
+            Operator::Ge => Some(x >= y),
+            Operator::Lt => Some(x < y),
+            Operator::Le => Some(x <= y),
+            Operator::Eq => Some(x == y),
+            Operator::Ne => Some(x != y),
+            _ => None,
+        }
+    }
+}
+
+/// Integer dtype of a column
+#[derive(Clone, Copy, PartialEq)]
+enum IntDtype {
+    I64,
+    I32,
+    I16,
+    I8,
+    Isize,
+    U64,
+    U32,
+    U16,
+    U8,
+    Usize,
+}
+
+/// Column values with null flags. Integers of any dtype are computed as i128
+/// so that u64 values beyond i64::MAX are kept
+enum Values {
+    Int(Vec<i128>, Vec<bool>, IntDtype),
+    Float(Vec<f64>, Vec<bool>),
+    Bool(Vec<bool>, Vec<bool>),
+}
+
+macro_rules! int_values {
+    ($values:expr, $dtype:ident) => {{
+        let (values, nulls): (Vec<i128>, Vec<bool>) =
+            $values.iter_raw().map(|(n, &v)| (v as i128, n)).unzip();
+        Values::Int(values, nulls, IntDtype::$dtype)
+    }}
+}
+
+macro_rules! int_array {
+    ($values:expr, $nulls:expr, $t:ident, $klass:ident) => {{
+        let mut nulls = $nulls;
+        let values: Vec<$t> = $values
+            .into_iter()
+            .zip(nulls.iter_mut())
+            .map(|(v, n)| {
+                if *n {
+                    return 0;
+                }
+                // out of range of the dtype is null
+                $t::try_from(v).unwrap_or_else(|_| {
+                    *n = true;
+                    0
+                })
+            })
+            .collect();
+        Array::$klass(to_nullvec(values, nulls))
+    }}
+}
+
+impl IntDtype {
+    /// Convert i128 values to Array of the dtype
+    fn to_array(self, values: Vec<i128>, nulls: Vec<bool>) -> Array {
+        match self {
+            IntDtype::I64 => int_array!(values, nulls, i64, Int64Array),
+            IntDtype::I32 => int_array!(values, nulls, i32, Int32Array),
+            IntDtype::I16 => int_array!(values, nulls, i16, Int16Array),
+            IntDtype::I8 => int_array!(values, nulls, i8, Int8Array),
+            IntDtype::Isize => int_array!(values, nulls, isize, IsizeArray),
+            IntDtype::U64 => int_array!(values, nulls, u64, UInt64Array),
+            IntDtype::U32 => int_array!(values, nulls, u32, UInt32Array),
+            IntDtype::U16 => int_array!(values, nulls, u16, UInt16Array),
+            IntDtype::U8 => int_array!(values, nulls, u8, UInt8Array),
+            IntDtype::Usize => int_array!(values, nulls, usize, UsizeArray),
+        }
+    }
+}
+
+impl Values {
+    fn from_array(array: &Array, op: Operator) -> Result<Values, Error> {
+        let values = match *array {
+            Array::Int64Array(ref v) => int_values!(v, I64),
+            Array::Int32Array(ref v) => int_values!(v, I32),
+            Array::Int16Array(ref v) => int_values!(v, I16),
+            Array::Int8Array(ref v) => int_values!(v, I8),
+            Array::IsizeArray(ref v) => int_values!(v, Isize),
+            Array::UInt64Array(ref v) => int_values!(v, U64),
+            Array::UInt32Array(ref v) => int_values!(v, U32),
+            Array::UInt16Array(ref v) => int_values!(v, U16),
+            Array::UInt8Array(ref v) => int_values!(v, U8),
+            Array::UsizeArray(ref v) => int_values!(v, Usize),
+            Array::Float64Array(_) |
+            Array::Float32Array(_) => {
+                let scalars: Vec<Scalar> = (0..array.len()).map(|i| array.iloc(&i)).collect();
+                Values::Float(
+                    scalars.iter().map(|x| unwrap_or_default(x.as_f64())).collect(),
+                    scalars.iter().map(|x| *x == Scalar::Null).collect(),
+                )
+            }
+            Array::BoolArray(ref v) => {
+                let (values, nulls): (Vec<bool>, Vec<bool>) =
+                    v.iter_raw().map(|(n, &x)| (x, n)).unzip();
+                Values::Bool(values, nulls)
+            }
+            Array::StringArray(_) => {
+                return Err(Error::InvalidOperation(format!(
+                    "unable to apply {} to non-numeric column (dtype: {})",
+                    op.name(),
+                    array.dtype()
+                )))
+            }
+        };
+        Ok(values)
+    }
+
+    /// All null values which has the same type
+    fn null_like(&self) -> Values {
+        match *self {
+            Values::Int(ref v, _, dtype) => Values::Int(vec![0; v.len()], vec![true; v.len()], dtype),
+            Values::Float(ref v, _) => Values::Float(vec![0.; v.len()], vec![true; v.len()]),
+            Values::Bool(ref v, _) => Values::Bool(vec![false; v.len()], vec![true; v.len()]),
+        }
+    }
+
+    fn into_float(self) -> (Vec<f64>, Vec<bool>) {
+        match self {
+            Values::Int(v, n, _) => (v.into_iter().map(|x| x as f64).collect(), n),
+            Values::Float(v, n) => (v, n),
+            Values::Bool(..) => unreachable!(),
+        }
+    }
+}
+
+fn unwrap_or_default<T>(value: Nullable<T>) -> T
+where
+    T: NullStorable + Default,
+{
+    match value {
+        Nullable::Value(v) => v,
+        Nullable::Null => T::default(),
+    }
+}
+
+/// Apply the operator to each aligned column
+fn compute<'a, 'b, 'c, I, C, R>(
+    frame: &DataFrame<I, C>,
+    other: &R,
+    op: Operator,
+) -> Result<DataFrame<'a, 'b, 'c, I, C>, Error>
+where
+    I: 'b + Clone + Eq + Hash,
+    C: 'c + Clone + Eq + Hash,
+    R: FrameOperand<I, C>,
+{
+    let aligned = other.align_with(frame);
+
+    let mut new_values: Vec<Cow<Array>> = Vec::with_capacity(aligned.columns.len());
+    for (left, right) in aligned.left.iter().zip(&aligned.right) {
+        let (left, right) = match (left.as_ref(), right.as_ref()) {
+            (Some(l), Some(r)) => (Values::from_array(l, op)?, Values::from_array(r, op)?),
+            (Some(l), None) => {
+                let l = Values::from_array(l, op)?;
+                let r = l.null_like();
+                (l, r)
+            }
+            (None, Some(r)) => {
+                let r = Values::from_array(r, op)?;
+                (r.null_like(), r)
+            }
+            (None, None) => unreachable!(),
+        };
+        new_values.push(Cow::Owned(apply(left, right, op, aligned.scalar)?));
+    }
+    Ok(DataFrame::from_cow(
+        new_values,
+        Cow::Owned(aligned.index),
+        Cow::Owned(aligned.columns),
+    ))
+}
+
+/// Apply the operator to values promoting integers to float if either is float.
+/// Integer result keeps the dtype if both sides (or left, if right is a scalar)
+/// have the same dtype, otherwise it is i64. Values out of range of the dtype,
+/// division by zero and overflow are null
+fn apply(left: Values, right: Values, op: Operator, scalar: bool) -> Result<Array, Error> {
+    match (left, right) {
+        (Values::Int(l, ln, ldtype), Values::Int(r, rn, rdtype)) => {
+            let func: fn(i128, i128) -> Option<i128> = match op {
+                Operator::Add => i128::checked_add,
+                Operator::Sub => i128::checked_sub,
+                Operator::Mul => i128::checked_mul,
+                Operator::Div => i128::checked_div,
+                Operator::Rem => i128::checked_rem,
+                Operator::BitAnd => |x, y| Some(x & y),
+                Operator::BitOr => |x, y| Some(x | y),
+                Operator::BitXor => |x, y| Some(x ^ y),
+                _ => return Ok(Array::BoolArray(compare(&l, &ln, &r, &rn, op))),
+            };
+            let dtype = if scalar || ldtype == rdtype { ldtype } else { IntDtype::I64 };
+            let (values, nulls) = elemwise(&l, &ln, &r, &rn, func);
+            Ok(dtype.to_array(values, nulls))
+        }
+        (Values::Bool(l, ln), Values::Bool(r, rn)) => {
+            let func: fn(bool, bool) -> Option<bool> = match op {
+                Operator::BitAnd => |x, y| Some(x & y),
+                Operator::BitOr => |x, y| Some(x | y),
+                Operator::BitXor => |x, y| Some(x ^ y),
+                Operator::Gt | Operator::Ge | Operator::Lt | Operator::Le | Operator::Eq |
+                Operator::Ne => return Ok(Array::BoolArray(compare(&l, &ln, &r, &rn, op))),
+                _ => {
+                    return Err(Error::InvalidOperation(
+                        format!("unable to apply {} to bool column", op.name()),
+                    ))
+                }
+            };
+            let (values, nulls) = elemwise(&l, &ln, &r, &rn, func);
+            Ok(Array::BoolArray(to_nullvec(values, nulls)))
+        }
+        (Values::Bool(..), _) |
+        (_, Values::Bool(..)) => Err(Error::InvalidOperation(format!(
+            "unable to apply {} to bool and numeric columns",
+            op.name()
+        ))),
+        (left, right) => {
+            let (l, ln) = left.into_float();
+            let (r, rn) = right.into_float();
+            let func: fn(f64, f64) -> Option<f64> = match op {
+                Operator::Add => |x, y| Some(x + y),
+                Operator::Sub => |x, y| Some(x - y),
+                Operator::Mul => |x, y| Some(x * y),
+                Operator::Div => |x, y| Some(x / y),
+                Operator::Rem => |x, y| Some(x % y),
+                Operator::BitAnd | Operator::BitOr | Operator::BitXor => {
+                    return Err(Error::InvalidOperation(
+                        format!("unable to apply {} to float column", op.name()),
+                    ))
+                }
+                _ => return Ok(Array::BoolArray(compare(&l, &ln, &r, &rn, op))),
+            };
+            let (values, nulls) = elemwise(&l, &ln, &r, &rn, func);
+            Ok(Array::Float64Array(to_nullvec(values, nulls)))
+        }
+    }
+}
+
+/// Result is null where either value is null or func returns None.
+/// Returns values and null flags
+fn elemwise<T, F>(
+    left: &[T],
+    lnulls: &[bool],
+    right: &[T],
+    rnulls: &[bool],
+    func: F,
+) -> (Vec<T>, Vec<bool>)
+where
+    T: Copy + Default,
+    F: Fn(T, T) -> Option<T>,
+{
+    let mut values: Vec<T> = Vec::with_capacity(left.len());
+    let mut mask: Vec<bool> = Vec::with_capacity(left.len());
+    for ((&x, &xn), (&y, &yn)) in left.iter().zip(lnulls).zip(right.iter().zip(rnulls)) {
+        match func(x, y) {
+            Some(v) if !xn && !yn => {
+                values.push(v);
+                mask.push(false);
+            }
+            _ => {
+                values.push(T::default());
+                mask.push(true);
+            }
+        }
+    }
+    (values, mask)
+}
+
+fn to_nullvec<T: NullStorable>(values: Vec<T>, mask: Vec<bool>) -> NullVec<T> {
+    if mask.contains(&true) {
+        NullVec::with_mask(values, Some(mask))
+    } else {
+        NullVec::new(values)
+    }
+}
+
+/// Null is compared as false
+fn compare<T>(left: &[T], lnulls: &[bool], right: &[T], rnulls: &[bool], op: Operator) -> NullVec<bool>
+where
+    T: PartialOrd,
+{
+    let values: Vec<bool> = left.iter()
+        .zip(lnulls)
+        .zip(right.iter().zip(rnulls))
+        .map(|((x, &xn), (y, &yn))| !xn && !yn && op.compare(x, y).unwrap())
+        .collect();
+    NullVec::new(values)
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Operators
+// /////////////////////////////////////////////////////////////////////////////
+
+macro_rules! define_frame_op {
+    ($t:ident, $m:ident, $try_m:ident, $op:ident) => {
+
+        impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+            where I: Clone + Eq + Hash,
+                  C: Clone + Eq + Hash {
+
+            /// Perform element-wise operation aligning both sides.
+            /// Err if the operation is not supported for any column
+            pub fn $try_m<R>(&self, other: R) -> Result<DataFrame<'v, 'i, 'c, I, C>, Error>
+                where R: FrameOperand<I, C> {
+                compute(self, &other, Operator::$op)
+            }
+        }
+
+        impl<'v, 'i, 'c, I, C, R> $t<R> for DataFrame<'v, 'i, 'c, I, C>
+            where I: Clone + Eq + Hash,
+                  C: Clone + Eq + Hash,
+                  R: FrameOperand<I, C> {
+
+            type Output = DataFrame<'v, 'i, 'c, I, C>;
+            fn $m(self, _rhs: R) -> Self::Output {
+                match self.$try_m(_rhs) {
+                    Ok(result) => result,
+                    Err(message) => panic!("{}", message),
+                }
+            }
+        }
+
+        impl<'v, 'i, 'c, 'l, I, C, R> $t<R> for &'l DataFrame<'v, 'i, 'c, I, C>
+            where I: Clone + Eq + Hash,
+                  C: Clone + Eq + Hash,
+                  R: FrameOperand<I, C> {
+
+            type Output = DataFrame<'l, 'l, 'l, I, C>;
+            fn $m(self, _rhs: R) -> Self::Output {
+                match self.$try_m(_rhs) {
+                    Ok(result) => result,
+                    Err(message) => panic!("{}", message),
+                }
+            }
+        }
+    }
+}
+
+define_frame_op!(Add, add, try_add, Add);
+define_frame_op!(Mul, mul, try_mul, Mul);
+define_frame_op!(Sub, sub, try_sub, Sub);
+define_frame_op!(Div, div, try_div, Div);
+define_frame_op!(Rem, rem, try_rem, Rem);
+define_frame_op!(BitAnd, bitand, try_bitand, BitAnd);
+define_frame_op!(BitOr, bitor, try_bitor, BitOr);
+define_frame_op!(BitXor, bitxor, try_bitxor, BitXor);
+
+// /////////////////////////////////////////////////////////////////////////////
+// Comparison
+// /////////////////////////////////////////////////////////////////////////////
+
+macro_rules! define_frame_compare {
+    ($m:ident, $try_m:ident, $op:ident) => {
+
+        impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+            where I: Clone + Eq + Hash,
+                  C: Clone + Eq + Hash {
+
+            /// Compare element-wise aligning both sides, null is compared as false.
+            /// Err if any column is not comparable
+            pub fn $try_m<R>(&self, other: R) -> Result<DataFrame<'v, 'i, 'c, I, C>, Error>
+                where R: FrameOperand<I, C> {
+                compute(self, &other, Operator::$op)
+            }
+
+            /// Compare element-wise aligning both sides, null is compared as false
+            pub fn $m<R>(&self, other: R) -> DataFrame<'v, 'i, 'c, I, C>
+                where R: FrameOperand<I, C> {
+                match self.$try_m(other) {
+                    Ok(result) => result,
+                    Err(message) => panic!("{}", message),
+                }
+            }
+        }
+    }
+}
+
+define_frame_compare!(gt, try_gt, Gt);
+define_frame_compare!(ge, try_ge, Ge);
+define_frame_compare!(lt, try_lt, Lt);
+define_frame_compare!(le, try_le, Le);
+define_frame_compare!(elem_eq, try_elem_eq, Eq);
+define_frame_compare!(elem_ne, try_elem_ne, Ne);
@@ -6,6 +6,7 @@ use nullvec::prelude::Append as NullVecAppend;
 
 use super::DataFrame;
 use algos::join::{JoinOp, HashJoin};
+use error::Error;
 use indexer::Indexer;
 use traits::{Slicer, RowIndex, Append, Concatenation, Join};
 
@@ -31,6 +32,18 @@ where
             Cow::Borrowed(self.columns.borrow()),
         )
     }
+
+    fn try_append<'o>(&'c self, other: &'o Self) -> Result<Self, Error> {
+        if self.columns != other.columns {
+            return Err(Error::ColumnsMismatch);
+        }
+        for (svalues, ovalues) in self.values.iter().zip(&other.values) {
+            if svalues.dtype() != ovalues.dtype() {
+                return Err(Error::DtypeMismatch(svalues.dtype(), ovalues.dtype()));
+            }
+        }
+        Ok(self.append(other))
+    }
 }
 
 impl<'v, 'i, 'c, I, C> Concatenation<'i> for DataFrame<'v, 'i, 'c, I, C>
@@ -54,6 +67,13 @@ where
             Cow::Owned(new_columns),
         )
     }
+
+    fn try_concat<'o>(&'i self, other: &'o Self) -> Result<Self, Error> {
+        if self.index != other.index {
+            return Err(Error::IndexMismatch);
+        }
+        Ok(self.concat(other))
+    }
 }
 
 impl<'v, 'i, 'c, I, C> Join for DataFrame<'v, 'i, 'c, I, C>
@@ -61,6 +81,9 @@ where
     I: Clone + Eq + Hash,
     C: Clone + Eq + Hash,
 {
+    type Output = Self;
+    type NullableOutput = Self;
+
     fn join_inner(&self, other: &Self) -> Self {
 
         let (new_index, lindexer, rindexer) =
@@ -82,4 +105,54 @@ where
             Cow::Owned(new_columns),
         )
     }
+
+    fn join_left(&self, other: &Self) -> Self {
+        let (new_index, lindexer, rindexer) =
+            HashJoin::left(&self.index.values, &other.index.values);
+        self.join_forced(other, new_index, &lindexer, &rindexer)
+    }
+
+    fn join_right(&self, other: &Self) -> Self {
+        let (new_index, lindexer, rindexer) =
+            HashJoin::right(&self.index.values, &other.index.values);
+        self.join_forced(other, new_index, &lindexer, &rindexer)
+    }
+
+    fn join_outer(&self, other: &Self) -> Self {
+        let (new_index, lindexer, rindexer) =
+            HashJoin::outer(&self.index.values, &other.index.values);
+        self.join_forced(other, new_index, &lindexer, &rindexer)
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Join using indexers returned from JoinOp, missing locations are filled with null
+    fn join_forced(
+        &self,
+        other: &Self,
+        new_index: Vec<I>,
+        lindexer: &[usize],
+        rindexer: &[usize],
+    ) -> Self {
+        let new_columns = self.columns.append(&other.columns);
+
+        let mut new_values: Vec<Cow<Array>> = Vec::with_capacity(new_columns.len());
+
+        for values in &self.values {
+            new_values.push(Cow::Owned(values.ilocs_forced(lindexer)));
+        }
+        for values in &other.values {
+            new_values.push(Cow::Owned(values.ilocs_forced(rindexer)));
+        }
+
+        DataFrame::from_cow(
+            new_values,
+            Cow::Owned(Indexer::new(new_index)),
+            Cow::Owned(new_columns),
+        )
+    }
 }
@@ -0,0 +1,165 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, NullVec};
+
+use super::DataFrame;
+use algos::window::{Window, Decay};
+use rolling::{Rolling, Ewm};
+use traits::{Slicer, Apply, BasicAggregation, NumericAggregation, ComparisonAggregation};
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Provide rolling window calculations over each numeric column
+    pub fn rolling(
+        &'i self,
+        window: usize,
+        min_periods: usize,
+        center: bool,
+    ) -> Rolling<'i, DataFrame<'v, 'i, 'c, I, C>> {
+        Rolling::new(self, window, min_periods, center)
+    }
+
+    /// Provide expanding window calculations over each numeric column
+    pub fn expanding(&'i self, min_periods: usize) -> Rolling<'i, DataFrame<'v, 'i, 'c, I, C>> {
+        Rolling::expanding(self, self.index.len(), min_periods)
+    }
+
+    /// Provide exponentially weighted calculations over each numeric column
+    pub fn ewm(&'i self, decay: Decay, adjust: bool) -> Ewm<'i, DataFrame<'v, 'i, 'c, I, C>> {
+        Ewm::new(self, decay, adjust)
+    }
+}
+
+/// Apply passed window function to each numeric column.
+/// Non-numeric columns are skipped.
+pub fn compute<'v, 'i, 'c, I, C>(
+    data: &'i DataFrame<'v, 'i, 'c, I, C>,
+    func: &dyn Fn(&[Option<f64>]) -> NullVec<f64>,
+) -> DataFrame<'i, 'i, 'i, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    let ndf = data.get_numeric_data();
+    let new_values: Vec<Cow<Array>> = ndf.values
+        .iter()
+        .map(|x| Cow::Owned(Array::Float64Array(func(&Window::from_array(x)))))
+        .collect();
+    DataFrame::from_cow(new_values, ndf.index, ndf.columns)
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Apply
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, 'c, I, C> Apply<'i, f64> for Rolling<'i, DataFrame<'v, 'i, 'c, I, C>>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    type In = Vec<f64>;
+    type FOut = f64;
+    type Out = DataFrame<'i, 'i, 'i, I, C>;
+
+    /// Apply passed function to non-null values in each window
+    fn apply<'f>(&'i self, func: &'f dyn Fn(&Self::In) -> Self::FOut) -> Self::Out {
+        compute(self.data, &|x| self.window.apply(x, func))
+    }
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Aggregation
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, 'c, I, C> BasicAggregation<'i> for Rolling<'i, DataFrame<'v, 'i, 'c, I, C>>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    type Kept = DataFrame<'i, 'i, 'i, I, C>;
+    type Counted = DataFrame<'i, 'i, 'i, I, C>;
+
+    fn sum(&'i self) -> Self::Kept {
+        compute(self.data, &|x| self.window.sum(x))
+    }
+
+    fn count(&'i self) -> Self::Counted {
+        compute(self.data, &|x| self.window.count(x))
+    }
+}
+
+impl<'v, 'i, 'c, I, C> NumericAggregation<'i> for Rolling<'i, DataFrame<'v, 'i, 'c, I, C>>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    type Coerced = DataFrame<'i, 'i, 'i, I, C>;
+
+    fn mean(&'i self) -> Self::Coerced {
+        compute(self.data, &|x| self.window.mean(x))
+    }
+
+    fn var(&'i self) -> Self::Coerced {
+        compute(self.data, &|x| self.window.var(x, 0))
+    }
+
+    fn unbiased_var(&'i self) -> Self::Coerced {
+        compute(self.data, &|x| self.window.var(x, 1))
+    }
+
+    fn std(&'i self) -> Self::Coerced {
+        compute(self.data, &|x| self.window.std(x, 0))
+    }
+
+    fn unbiased_std(&'i self) -> Self::Coerced {
+        compute(self.data, &|x| self.window.std(x, 1))
+    }
+}
+
+impl<'v, 'i, 'c, I, C> ComparisonAggregation<'i> for Rolling<'i, DataFrame<'v, 'i, 'c, I, C>>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    type Kept = DataFrame<'i, 'i, 'i, I, C>;
+
+    fn min(&'i self) -> Self::Kept {
+        compute(self.data, &|x| self.window.min(x))
+    }
+
+    fn max(&'i self) -> Self::Kept {
+        compute(self.data, &|x| self.window.max(x))
+    }
+}
+
+impl<'v, 'i, 'c, I, C> NumericAggregation<'i> for Ewm<'i, DataFrame<'v, 'i, 'c, I, C>>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    type Coerced = DataFrame<'i, 'i, 'i, I, C>;
+
+    fn mean(&'i self) -> Self::Coerced {
+        compute(self.data, &|x| self.weight.mean(x))
+    }
+
+    fn var(&'i self) -> Self::Coerced {
+        compute(self.data, &|x| self.weight.var(x, true))
+    }
+
+    fn unbiased_var(&'i self) -> Self::Coerced {
+        compute(self.data, &|x| self.weight.var(x, false))
+    }
+
+    fn std(&'i self) -> Self::Coerced {
+        compute(self.data, &|x| self.weight.std(x, true))
+    }
+
+    fn unbiased_std(&'i self) -> Self::Coerced {
+        compute(self.data, &|x| self.weight.std(x, false))
+    }
+}
@@ -0,0 +1,183 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::hash::Hash;
+use std::slice;
+use std::vec;
+
+use nullvec::prelude::Scalar;
+
+use formatting;
+use indexer::Indexer;
+use traits::{Slicer, IndexerIndex};
+
+#[derive(Clone)]
+pub struct Row<'c, C>
+where
+    C: 'c + Clone + Hash,
+{
+    /// Single row of DataFrame, mixed-dtype record keyed by column label.
+    /// C: type of columns
+    pub values: Vec<Scalar>,
+    pub columns: Cow<'c, Indexer<C>>,
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Misc
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'c, C> Row<'c, C>
+where
+    C: Clone + Eq + Hash,
+{
+    pub fn new<Y>(values: Vec<Scalar>, columns: Y) -> Self
+    where
+        Y: Into<Indexer<C>>,
+    {
+        let columns: Indexer<C> = columns.into();
+
+        assert!(values.len() == columns.len(), "Length mismatch!");
+
+        Row {
+            values,
+            columns: Cow::Owned(columns),
+        }
+    }
+
+    pub fn from_cow(values: Vec<Scalar>, columns: Cow<'c, Indexer<C>>) -> Self {
+
+        assert!(values.len() == columns.len(), "Length mismatch!");
+
+        Row {
+            values,
+            columns,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Whether the row has a value for the given column label
+    pub fn contains(&self, label: &C) -> bool {
+        self.columns.contains(label)
+    }
+
+    /// Get a value corresponding to given column label
+    pub fn get(&self, label: &C) -> &Scalar {
+        let loc = self.columns.get_loc(label);
+        self.iget(&loc)
+    }
+
+    /// Get a value corresponding to given column location
+    pub fn iget(&self, location: &usize) -> &Scalar {
+        &self.values[*location]
+    }
+
+    /// Return dtypes of each value
+    pub fn dtypes(&self) -> Vec<String> {
+        self.values.iter().map(|x| x.dtype()).collect()
+    }
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Eq
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'c, C> PartialEq for Row<'c, C>
+where
+    C: Clone + Hash + Eq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        (self.columns.eq(&other.columns)) && (self.values.eq(&other.values))
+    }
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Iterator
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'c, C> IntoIterator for Row<'c, C>
+where
+    C: Clone + Hash + Eq,
+{
+    type Item = Scalar;
+    type IntoIter = vec::IntoIter<Scalar>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
+
+impl<'c, C> Row<'c, C>
+where
+    C: Clone + Hash + Eq,
+{
+    pub fn iter(&self) -> slice::Iter<'_, Scalar> {
+        self.values.iter()
+    }
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Formatting
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'c, C> fmt::Display for Row<'c, C>
+where
+    C: Clone + Eq + Hash + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Row(columns={:?})", &self.columns.values)
+    }
+}
+
+impl<'c, C> fmt::Debug for Row<'c, C>
+where
+    C: Clone + Eq + Hash + ToString,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let str_columns = formatting::pad_string_vector(&self.columns.values);
+        let str_values = formatting::pad_string_vector(&self.values);
+
+        let mut result = vec![];
+        for (c, v) in str_columns.into_iter().zip(str_values) {
+            let row = [c, v];
+            result.push(row.join(" "));
+        }
+        // debug expression {:?} outputs linesep as character, do not use
+        write!(f, "{:}", &result.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use nullvec::prelude::Scalar;
+
+    use super::Row;
+
+    #[test]
+    fn test_row_get() {
+        let values = vec![Scalar::i64(1), Scalar::f64(1.5), Scalar::String("a".to_string())];
+        let row = Row::new(values, vec!["X", "Y", "Z"]);
+        assert_eq!(row.len(), 3);
+
+        assert_eq!(row.get(&"X"), &Scalar::i64(1));
+        assert_eq!(row.get(&"Z"), &Scalar::String("a".to_string()));
+        assert_eq!(row.iget(&1), &Scalar::f64(1.5));
+        assert!(row.contains(&"Y"));
+        assert!(!row.contains(&"W"));
+
+        let exp: Vec<String> = vec!["i64".to_string(), "f64".to_string(), "str".to_string()];
+        assert_eq!(row.dtypes(), exp);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_row_length_mismatch() {
+        Row::new(vec![Scalar::i64(1)], vec!["X", "Y"]);
+    }
+}
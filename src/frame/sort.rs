@@ -0,0 +1,88 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::hash::Hash;
+
+use nullvec::prelude::Array;
+
+use super::DataFrame;
+use algos::hash::HashableScalar;
+use algos::sort::NaPosition;
+use indexer::Indexer;
+use traits::{Slicer, IndexerIndex};
+
+//**********************************************
+//*Soat
+//**********************************************
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash + Ord,
+    C: Clone + Eq + Hash,
+{
+    pub fn sort_index(&self) -> Self {
+        let (indexer, sorted) = self.index.argsort();
+        self.sort_by_indexer(&indexer, sorted)
+    }
+}
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Sort rows by values of given columns. The sort is stable, and nulls are
+    /// placed according to na_position regardless of ascending
+    pub fn sort_values(&self, by: &[C], ascending: &[bool], na_position: NaPosition) -> Self {
+        assert!(!by.is_empty(), "at least one key column is required");
+        assert!(by.len() == ascending.len(), "Length mismatch!");
+
+        let locations = self.columns.get_locs(by);
+        let keys: Vec<Vec<HashableScalar>> = locations
+            .iter()
+            .map(|&loc| HashableScalar::from_array(&self.values[loc]))
+            .collect();
+
+        let mut indexer: Vec<usize> = (0..self.index.len()).collect();
+        // sort_by is stable
+        indexer.sort_by(|&l, &r| {
+            for (key, &asc) in keys.iter().zip(ascending) {
+                let ord = compare_keys(&key[l], &key[r], asc, na_position);
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+            }
+            Ordering::Equal
+        });
+        let sorted = self.index.reindex(&indexer);
+        self.sort_by_indexer(&indexer, sorted)
+    }
+
+    fn sort_by_indexer(&self, indexer: &[usize], index: Indexer<I>) -> Self {
+        let new_values: Vec<Cow<Array>> = self.values
+            .iter()
+            .map(|x| Cow::Owned(x.ilocs(indexer)))
+            .collect();
+        DataFrame::from_cow(new_values, Cow::Owned(index), self.columns.clone())
+    }
+}
+
+fn compare_keys(
+    left: &HashableScalar,
+    right: &HashableScalar,
+    ascending: bool,
+    na_position: NaPosition,
+) -> Ordering {
+    let nulls_first = na_position == NaPosition::First;
+    match (*left == HashableScalar::Null, *right == HashableScalar::Null) {
+        (true, true) => Ordering::Equal,
+        (true, false) => if nulls_first { Ordering::Less } else { Ordering::Greater },
+        (false, true) => if nulls_first { Ordering::Greater } else { Ordering::Less },
+        (false, false) => {
+            if ascending {
+                left.cmp(right)
+            } else {
+                right.cmp(left)
+            }
+        }
+    }
+}
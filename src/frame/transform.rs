@@ -0,0 +1,94 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use nullvec::prelude::Array;
+
+use super::DataFrame;
+use super::rolling::compute;
+use algos::transform::{Cumulative, Shift, to_options};
+use traits::Slicer;
+
+/// Apply Cumulative method to numeric Array keeping its dtype
+macro_rules! accumulate_array {
+    ($values:expr, $m:ident) => {
+        match *$values {
+            Array::Int64Array(ref v) => Cumulative::$m(&to_options(v)).into(),
+            Array::Int32Array(ref v) => Cumulative::$m(&to_options(v)).into(),
+            Array::Int16Array(ref v) => Cumulative::$m(&to_options(v)).into(),
+            Array::Int8Array(ref v) => Cumulative::$m(&to_options(v)).into(),
+            Array::IsizeArray(ref v) => Cumulative::$m(&to_options(v)).into(),
+            Array::UInt64Array(ref v) => Cumulative::$m(&to_options(v)).into(),
+            Array::UInt32Array(ref v) => Cumulative::$m(&to_options(v)).into(),
+            Array::UInt16Array(ref v) => Cumulative::$m(&to_options(v)).into(),
+            Array::UInt8Array(ref v) => Cumulative::$m(&to_options(v)).into(),
+            Array::UsizeArray(ref v) => Cumulative::$m(&to_options(v)).into(),
+            Array::Float64Array(ref v) => Cumulative::$m(&to_options(v)).into(),
+            Array::Float32Array(ref v) => Cumulative::$m(&to_options(v)).into(),
+            _ => panic!("unable to accumulate non-numeric column"),
+        }
+    }
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Transform
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, 'c, I, C> DataFrame<'v, 'i, 'c, I, C>
+where
+    I: Clone + Eq + Hash,
+    C: Clone + Eq + Hash,
+{
+    /// Cumulative sum of each numeric column. Non-numeric columns are skipped.
+    /// The dtype is kept, integer overflow is null.
+    pub fn cumsum(&'i self) -> DataFrame<'i, 'i, 'i, I, C> {
+        self.accumulate(&|x| accumulate_array!(x, sum))
+    }
+
+    /// Cumulative product of each numeric column. Non-numeric columns are skipped.
+    /// The dtype is kept, integer overflow is null.
+    pub fn cumprod(&'i self) -> DataFrame<'i, 'i, 'i, I, C> {
+        self.accumulate(&|x| accumulate_array!(x, prod))
+    }
+
+    /// Cumulative maximum of each numeric column. Non-numeric columns are skipped.
+    pub fn cummax(&'i self) -> DataFrame<'i, 'i, 'i, I, C> {
+        self.accumulate(&|x| accumulate_array!(x, max))
+    }
+
+    /// Cumulative minimum of each numeric column. Non-numeric columns are skipped.
+    pub fn cummin(&'i self) -> DataFrame<'i, 'i, 'i, I, C> {
+        self.accumulate(&|x| accumulate_array!(x, min))
+    }
+
+    fn accumulate(&'i self, func: &dyn Fn(&Array) -> Array) -> DataFrame<'i, 'i, 'i, I, C> {
+        let ndf = self.get_numeric_data();
+        let new_values: Vec<Cow<Array>> = ndf.values
+            .iter()
+            .map(|x| Cow::Owned(func(x)))
+            .collect();
+        DataFrame::from_cow(new_values, ndf.index, ndf.columns)
+    }
+
+    /// Difference from the row periods before of each numeric column.
+    /// Non-numeric columns are skipped.
+    pub fn diff(&'i self, periods: isize) -> DataFrame<'i, 'i, 'i, I, C> {
+        compute(self, &|x| Shift::diff(x, periods))
+    }
+
+    /// Ratio of change from the row periods before of each numeric column.
+    /// Non-numeric columns are skipped.
+    pub fn pct_change(&'i self, periods: isize) -> DataFrame<'i, 'i, 'i, I, C> {
+        compute(self, &|x| Shift::pct_change(x, periods))
+    }
+
+    /// Shift all columns by periods keeping the index. Positions which have
+    /// no source value are filled with null.
+    pub fn shift(&self, periods: isize) -> DataFrame<'v, 'i, 'c, I, C> {
+        let indexer = Shift::indexer(self.index.len(), periods);
+        let new_values: Vec<Cow<Array>> = self.values
+            .iter()
+            .map(|x| Cow::Owned(x.ilocs_forced(&indexer)))
+            .collect();
+        DataFrame::from_cow(new_values, self.index.clone(), self.columns.clone())
+    }
+}
@@ -1,33 +1,147 @@
+use std::collections::HashSet;
 use std::hash::Hash;
 
-use algos::grouper::{Grouper, HashGrouper};
-use traits::RowIndex;
+use nullvec::prelude::{Array, Scalar};
+use nullvec::prelude::BasicAggregation as NBasicAggregation;
+use nullvec::prelude::NumericAggregation as NNumericAggregation;
+use nullvec::prelude::ComparisonAggregation as NComparisonAggregation;
+
+use algos::grouper::{Grouper, HashGrouper, SortGrouper};
+use algos::hash::HashableScalar;
+use algos::join::USIZE_MISSING;
+use error::Error;
+use traits::{Slicer, RowIndex};
+
+/// Algorithm to group values
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GroupStrategy {
+    /// group by HashMap, suitable for unsorted or high-cardinality keys
+    Hash,
+    /// group by sorting keys, suitable for sorted or low-cardinality keys
+    Sort,
+}
+
+/// Options of groupby
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GroupByOptions {
+    /// whether to sort groups, groups are ordered by first appearance if false
+    pub sort: bool,
+    /// whether to exclude rows whose key is null
+    pub dropna: bool,
+    /// algorithm to group values
+    pub strategy: GroupStrategy,
+}
+
+impl Default for GroupByOptions {
+    fn default() -> Self {
+        GroupByOptions {
+            sort: true,
+            dropna: true,
+            strategy: GroupStrategy::Hash,
+        }
+    }
+}
 
 pub struct GroupBy<'a, D: 'a, G: Hash> {
     /// Grouped Series
     /// D: grouped data
     /// V: type of Group indexer
     pub data: &'a D,
-    pub grouper: HashGrouper<G>,
+    grouper: Box<dyn Grouper<G> + 'a>,
+    /// group keys in the order of results
+    keys: Vec<G>,
 }
 
 impl<'a, D, G> GroupBy<'a, D, G>
 where
     D: RowIndex<'a>,
-    G: Clone + Eq + Hash + Ord,
+    G: 'a + Clone + Eq + Hash + Ord,
 {
     pub fn new(data: &'a D, indexer: &[G]) -> Self {
+        GroupBy::with_strategy(data, indexer, GroupStrategy::Hash)
+    }
+
+    /// Group data using specified algorithm
+    pub fn with_strategy(data: &'a D, indexer: &[G], strategy: GroupStrategy) -> Self {
+        let options = GroupByOptions {
+            strategy,
+            ..GroupByOptions::default()
+        };
+        GroupBy::with_options(data, indexer, options, |_| false)
+    }
 
+    /// Group data by keys which may contain nulls. Keys which satisfy is_null
+    /// are regarded as null and excluded if options.dropna is true.
+    /// Use unsorted or unsorted_dropna if keys are not Ord
+    pub fn with_options<F>(data: &'a D, indexer: &[G], options: GroupByOptions, is_null: F) -> Self
+    where
+        F: Fn(&G) -> bool,
+    {
+        let grouper: Box<dyn Grouper<G>> = match options.strategy {
+            GroupStrategy::Hash => Box::new(HashGrouper::groupby(indexer)),
+            GroupStrategy::Sort => Box::new(SortGrouper::groupby(indexer)),
+        };
+        let by_appearance = !options.sort;
+        let mut grouped = GroupBy::from_grouper(data, indexer.len(), grouper, by_appearance, |k| {
+            options.dropna && is_null(k)
+        });
+        // SortGrouper already returns sorted keys
+        if options.sort && options.strategy == GroupStrategy::Hash {
+            grouped.keys.sort();
+        }
+        grouped
+    }
+}
+
+impl<'a, D, G> GroupBy<'a, D, G>
+where
+    D: RowIndex<'a>,
+    G: 'a + Clone + Eq + Hash,
+{
+    /// Group data without sorting, groups are ordered by first appearance.
+    /// Keys don't have to be Ord
+    pub fn unsorted(data: &'a D, indexer: &[G]) -> Self {
+        GroupBy::unsorted_dropna(data, indexer, |_| false)
+    }
+
+    /// Group data without sorting, excluding rows whose key satisfies is_null.
+    /// Keys don't have to be Ord
+    pub fn unsorted_dropna<F>(data: &'a D, indexer: &[G], is_null: F) -> Self
+    where
+        F: Fn(&G) -> bool,
+    {
+        let grouper = Box::new(HashGrouper::groupby(indexer));
+        GroupBy::from_grouper(data, indexer.len(), grouper, true, is_null)
+    }
+
+    /// Group data by keys of grouper, excluding keys which satisfy drop.
+    /// Groups are ordered by first appearance if by_appearance is true,
+    /// otherwise the order of grouper.keys() is kept
+    fn from_grouper<F>(
+        data: &'a D,
+        indexer_len: usize,
+        grouper: Box<dyn Grouper<G> + 'a>,
+        by_appearance: bool,
+        drop: F,
+    ) -> Self
+    where
+        F: Fn(&G) -> bool,
+    {
         assert!(
-            data.len() == indexer.len(),
+            data.len() == indexer_len,
             "Series and Indexer length are different"
         );
 
-        let grouper: HashGrouper<G> = HashGrouper::groupby(indexer);
+        let mut keys: Vec<G> = grouper.keys();
+        keys.retain(|k| !drop(k));
+        if by_appearance {
+            sort_by_appearance(&mut keys, grouper.as_ref());
+        }
 
         GroupBy {
-            data: data,
-            grouper: grouper,
+            data,
+            grouper,
+            keys,
         }
     }
 
@@ -39,9 +153,173 @@ where
         }
     }
 
+    /// Get data of the group, Err if group doesn't exist
+    pub fn try_get_group(&self, group: &G) -> Result<D, Error> {
+        match self.grouper.get(group) {
+            Some(locs) => Ok(self.data.ilocs(locs)),
+            None => Err(Error::GroupNotFound),
+        }
+    }
+
     pub fn groups(&self) -> Vec<G> {
-        let mut keys: Vec<G> = self.grouper.keys();
-        keys.sort();
-        keys
+        self.keys.clone()
+    }
+
+    /// Locations of each group, in the same order as groups
+    pub fn locations(&self) -> Vec<&[usize]> {
+        self.keys
+            .iter()
+            .map(|k| self.grouper.get(k).unwrap())
+            .collect()
+    }
+}
+
+impl<'a, D> GroupBy<'a, D, HashableScalar>
+where
+    D: RowIndex<'a>,
+{
+    /// Group data by values of Array, which may contain nulls
+    pub fn from_array(data: &'a D, indexer: &Array, options: GroupByOptions) -> Self {
+        let keys = HashableScalar::from_array(indexer);
+        GroupBy::with_options(data, &keys, options, |x| *x == HashableScalar::Null)
+    }
+}
+
+/// Sort keys by the location where each group first appears
+fn sort_by_appearance<G>(keys: &mut [G], grouper: &dyn Grouper<G>) {
+    keys.sort_by_key(|k| grouper.get(k).unwrap()[0]);
+}
+
+/// Aggregation function to be passed to agg
+#[derive(Clone, Copy)]
+pub enum AggFunc<'f> {
+    Sum,
+    Mean,
+    Min,
+    Max,
+    /// number of values which are not Null
+    Count,
+    /// number of unique values, Null is excluded
+    NUnique,
+    /// first value which is not Null
+    First,
+    /// last value which is not Null
+    Last,
+    /// user defined function with its name used as a label
+    Apply(&'f str, &'f dyn Fn(&Array) -> Scalar),
+}
+
+impl<'f> AggFunc<'f> {
+    /// Label of the function used in the result
+    pub fn name(&self) -> &'f str {
+        match *self {
+            AggFunc::Sum => "sum",
+            AggFunc::Mean => "mean",
+            AggFunc::Min => "min",
+            AggFunc::Max => "max",
+            AggFunc::Count => "count",
+            AggFunc::NUnique => "nunique",
+            AggFunc::First => "first",
+            AggFunc::Last => "last",
+            AggFunc::Apply(name, _) => name,
+        }
+    }
+
+    fn apply(&self, values: &Array) -> Scalar {
+        match *self {
+            AggFunc::Sum => values.sum(),
+            AggFunc::Mean => values.mean().into(),
+            AggFunc::Min if values.is_numeric() => values.min(),
+            AggFunc::Max if values.is_numeric() => values.max(),
+            // non-numeric values are compared as HashableScalar, Null is excluded
+            AggFunc::Min => {
+                scalars_not_null(values)
+                    .min_by_key(HashableScalar::new)
+                    .unwrap_or(Scalar::Null)
+            }
+            AggFunc::Max => {
+                scalars_not_null(values)
+                    .max_by_key(HashableScalar::new)
+                    .unwrap_or(Scalar::Null)
+            }
+            AggFunc::Count => {
+                let count = HashableScalar::from_array(values)
+                    .iter()
+                    .filter(|&x| *x != HashableScalar::Null)
+                    .count();
+                Scalar::usize(count)
+            }
+            AggFunc::NUnique => {
+                let uniques: HashSet<HashableScalar> = HashableScalar::from_array(values)
+                    .into_iter()
+                    .filter(|x| *x != HashableScalar::Null)
+                    .collect();
+                Scalar::usize(uniques.len())
+            }
+            AggFunc::First => {
+                (0..values.len())
+                    .map(|i| values.iloc(&i))
+                    .find(|x| *x != Scalar::Null)
+                    .unwrap_or(Scalar::Null)
+            }
+            AggFunc::Last => {
+                (0..values.len())
+                    .rev()
+                    .map(|i| values.iloc(&i))
+                    .find(|x| *x != Scalar::Null)
+                    .unwrap_or(Scalar::Null)
+            }
+            AggFunc::Apply(_, func) => func(values),
+        }
+    }
+
+    /// Aggregate values of each group specified by locations.
+    /// Err if Sum or Mean is applied to non-numeric values
+    pub fn aggregate(&self, values: &Array, locations: &[&[usize]]) -> Result<Array, Error> {
+        match *self {
+            AggFunc::Sum | AggFunc::Mean if !values.is_numeric() => {
+                return Err(Error::InvalidOperation(format!(
+                    "{} requires numeric values (dtype: {})",
+                    self.name(),
+                    values.dtype()
+                )));
+            }
+            _ => {}
+        }
+        let aggregated: Vec<Scalar> = locations
+            .iter()
+            .map(|locs| self.apply(&values.ilocs(locs)))
+            .collect();
+        // used to determine dtype when all results are Null
+        let like = match *self {
+            AggFunc::Mean => Array::new(Vec::<f64>::new()),
+            _ => values.ilocs(&[]),
+        };
+        Ok(scalars_to_array(aggregated, &like))
+    }
+}
+
+/// Scalars which are not Null
+fn scalars_not_null<'a>(values: &'a Array) -> Box<dyn Iterator<Item = Scalar> + 'a> {
+    Box::new((0..values.len()).map(move |i| values.iloc(&i)).filter(|x| *x != Scalar::Null))
+}
+
+/// Create Array from Scalars which may contain Null.
+/// If all values are Null, the result has the same dtype as like.
+pub fn scalars_to_array(values: Vec<Scalar>, like: &Array) -> Array {
+    let mut not_null: Vec<Scalar> = Vec::with_capacity(values.len());
+    let mut locations: Vec<usize> = Vec::with_capacity(values.len());
+    for value in values.into_iter() {
+        if value == Scalar::Null {
+            locations.push(USIZE_MISSING);
+        } else {
+            locations.push(not_null.len());
+            not_null.push(value);
+        }
+    }
+    if not_null.is_empty() {
+        like.ilocs_forced(&locations)
+    } else {
+        Array::from(not_null).ilocs_forced(&locations)
     }
 }
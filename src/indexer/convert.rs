@@ -3,9 +3,9 @@ use std::hash::Hash;
 
 use super::Indexer;
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// From / Into
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// From / Into
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<T: Clone + Eq + Hash> From<Vec<T>> for Indexer<T> {
     fn from(values: Vec<T>) -> Self {
@@ -19,9 +19,9 @@ impl<T: Clone + Hash> Into<Vec<T>> for Indexer<T> {
     }
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Clone on Write
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Clone on Write
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<'a, T: Clone + Hash> Into<Cow<'a, Indexer<T>>> for Indexer<T> {
     fn into(self) -> Cow<'a, Self> {
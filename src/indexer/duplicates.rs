@@ -0,0 +1,43 @@
+use std::hash::Hash;
+
+use super::Indexer;
+use algos::duplicates::Duplicates;
+use traits::Slicer;
+
+//**********************************************
+//*Duplicates
+//**********************************************
+
+impl<U> Indexer<U>
+where
+    U: Clone + Eq + Hash,
+{
+    /// Return whether each label is duplicated or not
+    pub fn duplicated(&self, keep: Duplicates) -> Vec<bool> {
+        Duplicates::duplicated(&self.values, keep)
+    }
+
+    /// Return Indexer without duplicated labels
+    pub fn drop_duplicates(&self, keep: Duplicates) -> Self {
+        let flags: Vec<bool> = self.duplicated(keep).iter().map(|x| !x).collect();
+        self.blocs(&flags)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::Indexer;
+    use algos::duplicates::Duplicates;
+
+    #[test]
+    fn test_index_duplicated() {
+        let idx = Indexer::new(vec!["a", "b", "a", "c", "b"]);
+        assert_eq!(idx.duplicated(Duplicates::First),
+                   vec![false, false, true, false, true]);
+        assert_eq!(idx.duplicated(Duplicates::Last),
+                   vec![true, true, false, false, false]);
+        assert_eq!(idx.drop_duplicates(Duplicates::None), Indexer::new(vec!["c"]));
+    }
+}
@@ -0,0 +1,46 @@
+use std::hash::Hash;
+
+use super::Indexer;
+use algos::factorize::Factorize;
+use traits::Slicer;
+
+//**********************************************
+//*Factorize
+//**********************************************
+
+impl<U> Indexer<U>
+where
+    U: Clone + Eq + Hash + Ord,
+{
+    /// Encode labels to integer codes, returns codes and unique labels.
+    /// Codes are assigned in appearance order, or sorted order if sort is true
+    pub fn factorize(&self, sort: bool) -> (Vec<usize>, Self) {
+        let (codes, locations) = Factorize::factorize(&self.values);
+        if sort {
+            let (codes, locations) = Factorize::sort_codes(&self.values, codes, locations);
+            (codes, self.ilocs(&locations))
+        } else {
+            (codes, self.ilocs(&locations))
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::Indexer;
+
+    #[test]
+    fn test_index_factorize() {
+        let idx = Indexer::new(vec!["b", "a", "b", "c"]);
+
+        let (codes, uniques) = idx.factorize(false);
+        assert_eq!(codes, vec![0, 1, 0, 2]);
+        assert_eq!(uniques, Indexer::new(vec!["b", "a", "c"]));
+
+        let (codes, uniques) = idx.factorize(true);
+        assert_eq!(codes, vec![1, 0, 1, 2]);
+        assert_eq!(uniques, Indexer::new(vec!["a", "b", "c"]));
+    }
+}
@@ -7,9 +7,12 @@ use std::slice;
 use std::vec;
 
 use nullvec::prelude::dev::algos::Indexing;
+use error::Error;
 use traits::{Slicer, IndexerIndex, Append};
 
 mod convert;
+mod duplicates;
+mod factorize;
 mod formatting;
 mod indexing;
 mod ops;
@@ -23,12 +26,15 @@ pub struct Indexer<U: Clone + Hash> {
 
     // provides interior mutability
     // ToDo: use Cow?
+    // first location of each label
     htable: RefCell<HashMap<U, usize>>,
+    // all locations of labels which appear more than once
+    duplicates: RefCell<HashMap<U, Vec<usize>>>,
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Constructor
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Constructor
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<U> Indexer<U>
 where
@@ -41,15 +47,16 @@ where
 
     pub fn new(values: Vec<U>) -> Self {
         Indexer {
-            values: values,
+            values,
             htable: RefCell::new(HashMap::new()),
+            duplicates: RefCell::new(HashMap::new()),
         }
     }
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Indexing
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Indexing
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<U> Slicer for Indexer<U>
 where
@@ -101,46 +108,111 @@ where
         self.htable.borrow().contains_key(label)
     }
 
+    /// Whether all labels are unique or not
+    fn is_unique(&self) -> bool {
+        self.init_state();
+        self.duplicates.borrow().is_empty()
+    }
+
     fn push(&mut self, label: U) {
+        self.init_state();
+
         let loc = self.len();
-        // ToDo: merge with init_state
-        let mut htable = self.htable.borrow_mut();
-        match htable.entry(label.clone()) {
-            Entry::Occupied(_) => panic!("duplicates are not allowed"),
-            Entry::Vacant(e) => e.insert(loc),
-        };
+        self.insert_state(label.clone(), loc);
         self.values.push(label);
     }
 
-    /// Return label location (usize) corresponding to given label (Scalar)
+    /// Return label location (usize) corresponding to given label (Scalar).
+    /// Panics if label doesn't exist or is duplicated, use get_all_locs for
+    /// duplicated labels
     fn get_loc(&self, label: &U) -> usize {
+        match self.try_get_loc(label) {
+            Ok(loc) => loc,
+            Err(message) => panic!("{}", message),
+        }
+    }
+
+    /// Return label location corresponding to given label.
+    /// Err if label doesn't exist or is duplicated
+    fn try_get_loc(&self, label: &U) -> Result<usize, Error> {
+        self.init_state();
+        if self.duplicates.borrow().contains_key(label) {
+            return Err(Error::DuplicatedLabel);
+        }
+        self.htable.borrow().get(label).cloned().ok_or(Error::LabelNotFound)
+    }
+
+    /// Return all label locations (Vector) corresponding to given label (Scalar).
+    /// Panics if label doesn't exist
+    fn get_all_locs(&self, label: &U) -> Vec<usize> {
+        match self.try_get_all_locs(label) {
+            Ok(locs) => locs,
+            Err(message) => panic!("{}", message),
+        }
+    }
+
+    /// Return all label locations corresponding to given label.
+    /// Err if label doesn't exist
+    fn try_get_all_locs(&self, label: &U) -> Result<Vec<usize>, Error> {
         self.init_state();
-        *self.htable.borrow().get(label).unwrap()
+        if let Some(locs) = self.duplicates.borrow().get(label) {
+            return Ok(locs.clone());
+        }
+        match self.htable.borrow().get(label) {
+            Some(loc) => Ok(vec![*loc]),
+            None => Err(Error::LabelNotFound),
+        }
     }
 
-    /// Return label locations (Vector) corresponding to given labels (Vector)
+    /// Return label locations (Vector) corresponding to given labels (Vector).
+    /// All the locations are returned for duplicated labels
     fn get_locs(&self, labels: &[U]) -> Vec<usize> {
-        labels.iter().map(|label| self.get_loc(label)).collect()
+        if self.is_unique() {
+            labels.iter().map(|label| self.get_loc(label)).collect()
+        } else {
+            labels
+                .iter()
+                .flat_map(|label| self.get_all_locs(label))
+                .collect()
+        }
     }
 
     fn init_state(&self) {
         // update htable
-        let mut htable = self.htable.borrow_mut();
-        if htable.len() != 0 {
+        if self.htable.borrow().len() != 0 {
             return;
         }
         for (loc, label) in self.values.iter().enumerate() {
-            match htable.entry(label.clone()) {
-                Entry::Occupied(_) => panic!("duplicates are not allowed"),
-                Entry::Vacant(e) => e.insert(loc),
-            };
+            self.insert_state(label.clone(), loc);
         }
     }
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Append
-/// /////////////////////////////////////////////////////////////////////////////
+impl<U> Indexer<U>
+where
+    U: Clone + Eq + Hash,
+{
+    /// Register label location to hashtables
+    fn insert_state(&self, label: U, loc: usize) {
+        let mut htable = self.htable.borrow_mut();
+        match htable.entry(label) {
+            Entry::Occupied(e) => {
+                let mut duplicates = self.duplicates.borrow_mut();
+                duplicates
+                    .entry(e.key().clone())
+                    .or_insert_with(|| vec![*e.get()])
+                    .push(loc);
+            }
+            Entry::Vacant(e) => {
+                e.insert(loc);
+            }
+        };
+    }
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Append
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<'a, T> Append<'a> for Indexer<T>
 where
@@ -151,11 +223,16 @@ where
         new_values.append(&mut other.values.clone());
         Indexer::new(new_values)
     }
+
+    /// Appending labels never fails, duplicated labels are allowed
+    fn try_append(&self, other: &Self) -> Result<Self, Error> {
+        Ok(self.append(other))
+    }
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Eq
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Eq
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<U> PartialEq for Indexer<U>
 where
@@ -166,9 +243,9 @@ where
     }
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Iterator
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Iterator
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<U> IntoIterator for Indexer<U>
 where
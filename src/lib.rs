@@ -4,6 +4,8 @@ extern crate num;
 extern crate nullvec;
 
 mod algos;
+mod block;
+mod error;
 mod formatting;
 mod frame;
 mod groupby;
@@ -11,7 +13,10 @@ mod indexer;
 mod io;
 #[macro_use]
 mod macros;
+mod rolling;
 mod series;
 mod traits;
 
 pub mod prelude;
+
+pub use error::Error;
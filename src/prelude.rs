@@ -6,8 +6,19 @@ pub use nullvec::prelude::BasicAggregation as NBasicAggregation;
 pub use nullvec::prelude::NumericAggregation as NNumericAggregation;
 pub use nullvec::prelude::ComparisonAggregation as NComparisonAggregation;
 
-pub use frame::DataFrame;
+pub use algos::duplicates::Duplicates;
+pub use algos::fill::{FillNA, Interpolation};
+pub use algos::hash::{HashableScalar, HashableTuple};
+pub use algos::join::USIZE_MISSING;
+pub use algos::sort::NaPosition;
+pub use algos::window::Decay;
+pub use block::Block;
+pub use error::Error;
+pub use frame::{DataFrame, Column, Row, JoinHow, MergeValidate, Axis, DropHow,
+                FrameOperand, ColumnWise};
+pub use groupby::{GroupBy, GroupByOptions, GroupStrategy, AggFunc};
 pub use indexer::Indexer;
+pub use rolling::{Rolling, Ewm};
 pub use series::Series;
-pub use traits::{Slicer, IndexerIndex, RowIndex, ColIndex, Append, Concatenation, Join, Apply,
+pub use traits::{ArrayElement, Slicer, IndexerIndex, RowIndex, ColIndex, Append, Concatenation, Join, Apply,
                  BasicAggregation, NumericAggregation, ComparisonAggregation, Description};
@@ -0,0 +1,41 @@
+use algos::window::{Window, Decay, ExpWeight};
+
+pub struct Rolling<'a, D: 'a> {
+    /// Moving window over rows
+    /// D: data to compute rolling statistics
+    pub data: &'a D,
+    pub window: Window,
+}
+
+impl<'a, D> Rolling<'a, D> {
+    pub fn new(data: &'a D, window: usize, min_periods: usize, center: bool) -> Self {
+        Rolling {
+            data,
+            window: Window::new(window, min_periods, center),
+        }
+    }
+
+    /// Window which covers all rows up to the current one
+    pub fn expanding(data: &'a D, len: usize, min_periods: usize) -> Self {
+        Rolling {
+            data,
+            window: Window::expanding(len, min_periods),
+        }
+    }
+}
+
+pub struct Ewm<'a, D: 'a> {
+    /// Exponentially weighted calculations over rows
+    /// D: data to compute weighted statistics
+    pub data: &'a D,
+    pub weight: ExpWeight,
+}
+
+impl<'a, D> Ewm<'a, D> {
+    pub fn new(data: &'a D, decay: Decay, adjust: bool) -> Self {
+        Ewm {
+            data,
+            weight: ExpWeight::new(decay.alpha(), adjust),
+        }
+    }
+}
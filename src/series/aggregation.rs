@@ -5,7 +5,7 @@ use std::ops::{Add, Sub, Div};
 use super::Series;
 use algos::computation::{Aggregation, NanMinMax};
 use algos::counter::Counter;
-use traits::{Apply, BasicAggregation, NumericAggregation, ComparisonAggregation, Description};
+use traits::{BasicAggregation, NumericAggregation, ComparisonAggregation, Description};
 
 
 impl<'v, 'i, V, I> BasicAggregation<'i> for Series<'v, 'i, V, I>
@@ -17,11 +17,11 @@ where
     type Counted = usize;
 
     fn sum(&'i self) -> Self::Kept {
-        self.apply(&Aggregation::vec_sum)
+        Aggregation::vec_sum(&self.valid_values())
     }
 
     fn count(&'i self) -> Self::Counted {
-        self.apply(&Aggregation::vec_count)
+        Aggregation::vec_count(&self.valid_values())
     }
 }
 
@@ -39,23 +39,23 @@ where
     type Coerced = f64;
 
     fn mean(&'i self) -> Self::Coerced {
-        self.apply(&Aggregation::vec_mean)
+        Aggregation::vec_mean(&self.valid_values())
     }
 
     fn var(&'i self) -> Self::Coerced {
-        self.apply(&Aggregation::vec_var)
+        Aggregation::vec_var(&self.valid_values())
     }
 
     fn unbiased_var(&'i self) -> Self::Coerced {
-        self.apply(&Aggregation::vec_unbiased_var)
+        Aggregation::vec_unbiased_var(&self.valid_values())
     }
 
     fn std(&'i self) -> Self::Coerced {
-        self.apply(&Aggregation::vec_std)
+        Aggregation::vec_std(&self.valid_values())
     }
 
     fn unbiased_std(&'i self) -> Self::Coerced {
-        self.apply(&Aggregation::vec_unbiased_std)
+        Aggregation::vec_unbiased_std(&self.valid_values())
     }
 }
 
@@ -67,11 +67,11 @@ where
     type Kept = V;
 
     fn min(&'i self) -> Self::Kept {
-        self.apply(&Aggregation::vec_min)
+        Aggregation::vec_min(&self.valid_values())
     }
 
     fn max(&'i self) -> Self::Kept {
-        self.apply(&Aggregation::vec_max)
+        Aggregation::vec_max(&self.valid_values())
     }
 }
 
@@ -90,7 +90,7 @@ where
 
     fn describe(&'i self) -> Self::Described {
         let new_index: Vec<&str> = vec!["count", "mean", "std", "min", "max"];
-        let count: f64 = Aggregation::vec_count(&self.values) as f64;
+        let count: f64 = Aggregation::vec_count(&self.valid_values()) as f64;
 
         let min = ToPrimitive::to_f64(&self.min()).unwrap();
         let max = ToPrimitive::to_f64(&self.max()).unwrap();
@@ -108,7 +108,8 @@ where
     I: Clone + Eq + Hash,
 {
     pub fn value_counts<'a>(&self) -> Series<'a, 'a, usize, V> {
-        let c = Counter::new(&self.values);
+        let values = self.valid_values();
+        let c = Counter::new(&values);
         let (keys, counts) = c.get_results();
         Series::new(counts, keys)
     }
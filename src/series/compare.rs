@@ -0,0 +1,117 @@
+use std::borrow::{Borrow, Cow};
+use std::hash::Hash;
+use std::ops::Not;
+
+use super::Series;
+
+// /////////////////////////////////////////////////////////////////////////////
+// Comparison
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + PartialOrd,
+    I: Clone + Eq + Hash,
+{
+    /// Return bool Series which is true where the value is greater than other.
+    /// Comparisons with null are false.
+    pub fn gt(&self, other: &V) -> Series<'i, 'i, bool, I> {
+        self.compare(&|x| x > other)
+    }
+
+    /// Return bool Series which is true where the value is greater than or equal to other.
+    /// Comparisons with null are false.
+    pub fn ge(&self, other: &V) -> Series<'i, 'i, bool, I> {
+        self.compare(&|x| x >= other)
+    }
+
+    /// Return bool Series which is true where the value is less than other.
+    /// Comparisons with null are false.
+    pub fn lt(&self, other: &V) -> Series<'i, 'i, bool, I> {
+        self.compare(&|x| x < other)
+    }
+
+    /// Return bool Series which is true where the value is less than or equal to other.
+    /// Comparisons with null are false.
+    pub fn le(&self, other: &V) -> Series<'i, 'i, bool, I> {
+        self.compare(&|x| x <= other)
+    }
+
+    /// Return bool Series which is true where the value is between left and right,
+    /// both inclusive. Comparisons with null are false.
+    pub fn between(&self, left: &V, right: &V) -> Series<'i, 'i, bool, I> {
+        self.compare(&|x| left <= x && x <= right)
+    }
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + PartialEq,
+    I: Clone + Eq + Hash,
+{
+    /// Return bool Series which is true where the value is equal to other.
+    /// Comparisons with null are false.
+    pub fn elem_eq(&self, other: &V) -> Series<'i, 'i, bool, I> {
+        self.compare(&|x| x == other)
+    }
+
+    /// Return bool Series which is true where the value is not equal to other.
+    /// Comparisons with null are false.
+    pub fn elem_ne(&self, other: &V) -> Series<'i, 'i, bool, I> {
+        self.compare(&|x| x != other)
+    }
+
+    /// Return bool Series which is true where the value is contained in values.
+    /// Null is never contained.
+    pub fn isin(&self, values: &[V]) -> Series<'i, 'i, bool, I> {
+        self.compare(&|x| values.contains(x))
+    }
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone,
+    I: Clone + Eq + Hash,
+{
+    fn compare(&self, func: &dyn Fn(&V) -> bool) -> Series<'i, 'i, bool, I> {
+        let new_values: Vec<bool> = self.values
+            .iter()
+            .zip(self.null_flags())
+            .map(|(x, is_null)| !is_null && func(x))
+            .collect();
+        Series::from_cow(Cow::Owned(new_values), self.index.clone())
+    }
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Not
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, V, I, O> Not for Series<'v, 'i, V, I>
+where
+    V: Clone + Not<Output = O>,
+    I: Clone + Eq + Hash,
+    O: 'v + Clone,
+{
+    type Output = Series<'v, 'i, O, I>;
+
+    fn not(self) -> Self::Output {
+        let new_values: Vec<O> = self.values.iter().map(|x| !x.clone()).collect();
+        Series::from_cow(Cow::Owned(new_values), self.index).set_mask(self.mask)
+    }
+}
+
+impl<'v, 'i, 'l, V, I, O> Not for &'l Series<'v, 'i, V, I>
+where
+    V: Clone + Not<Output = O>,
+    I: Clone + Eq + Hash,
+    O: 'l + Clone,
+{
+    type Output = Series<'l, 'l, O, I>;
+
+    fn not(self) -> Self::Output {
+        let new_values: Vec<O> = self.values.iter().map(|x| !x.clone()).collect();
+        Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(self.index.borrow()))
+            .set_mask(self.mask.clone())
+    }
+}
@@ -0,0 +1,85 @@
+use std::borrow::{Borrow, Cow};
+use std::hash::Hash;
+
+use super::Series;
+use nullvec::prelude::dev::algos::Indexing;
+use algos::duplicates::Duplicates;
+use traits::Slicer;
+
+//**********************************************
+//*Duplicates
+//**********************************************
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + Eq + Hash,
+    I: Clone + Eq + Hash,
+{
+    /// Return whether each value is duplicated or not. Nulls are regarded
+    /// as the same value
+    pub fn duplicated(&self, keep: Duplicates) -> Series<'_, '_, bool, I> {
+        let new_values = self.duplicated_flags(keep);
+        Series::from_cow(Cow::Owned(new_values), Cow::Borrowed(self.index.borrow()))
+    }
+
+    /// Return Series without duplicated values
+    pub fn drop_duplicates(&self, keep: Duplicates) -> Self {
+        let flags: Vec<bool> = self.duplicated_flags(keep)
+            .iter()
+            .map(|x| !x)
+            .collect();
+        let new_values: Vec<V> = Indexing::blocs(&self.values, &flags);
+        let new_index = self.index.blocs(&flags);
+        let new_mask = self.mask.as_ref().map(|x| Indexing::blocs(x, &flags));
+        Series::new(new_values, new_index).set_mask(new_mask)
+    }
+
+    fn duplicated_flags(&self, keep: Duplicates) -> Vec<bool> {
+        match self.mask {
+            Some(ref mask) => {
+                // placeholder values behind nulls must not be compared
+                let keys: Vec<Option<&V>> = self.values
+                    .iter()
+                    .zip(mask.iter())
+                    .map(|(v, &m)| if m { None } else { Some(v) })
+                    .collect();
+                Duplicates::duplicated(&keys, keep)
+            }
+            None => Duplicates::duplicated(&self.values, keep),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::super::Series;
+    use algos::duplicates::Duplicates;
+
+    #[test]
+    fn test_series_duplicated() {
+        let s = Series::new(vec![1, 2, 1, 3], vec!["a", "b", "c", "d"]);
+
+        let exp = Series::new(vec![false, false, true, false], vec!["a", "b", "c", "d"]);
+        assert_eq!(s.duplicated(Duplicates::First), exp);
+
+        let exp = Series::new(vec![true, false, true, false], vec!["a", "b", "c", "d"]);
+        assert_eq!(s.duplicated(Duplicates::None), exp);
+    }
+
+    #[test]
+    fn test_series_duplicated_null() {
+        // placeholder behind null is the same as a valid value
+        let s = Series::with_mask(vec![1, 1, 2, 0, 0],
+                                  vec!["a", "b", "c", "d", "e"],
+                                  vec![false, true, false, true, false]);
+
+        let exp = Series::new(vec![false, false, false, true, false],
+                              vec!["a", "b", "c", "d", "e"]);
+        assert_eq!(s.duplicated(Duplicates::First), exp);
+
+        let exp = Series::with_mask(vec![1, 1, 2, 0], vec!["a", "b", "c", "e"],
+                                    vec![false, true, false, false]);
+        assert_eq!(s.drop_duplicates(Duplicates::First), exp);
+    }
+}
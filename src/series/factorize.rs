@@ -0,0 +1,27 @@
+use std::hash::Hash;
+
+use nullvec::prelude::Scalar;
+
+use super::Series;
+use nullvec::prelude::dev::algos::Indexing;
+use algos::factorize::Factorize;
+
+//**********************************************
+//*Factorize
+//**********************************************
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone,
+    I: Clone + Eq + Hash,
+    Scalar: From<V>,
+{
+    /// Encode values to integer codes, returns codes and unique values.
+    /// Codes are assigned in appearance order, or sorted order if sort is true.
+    /// Null (and NaN) is encoded as USIZE_MISSING and is not included in uniques
+    pub fn factorize(&self, sort: bool) -> (Vec<usize>, Vec<V>) {
+        let (codes, locations) =
+            Factorize::factorize_scalars(&self.values, &self.null_flags(), sort);
+        (codes, Indexing::reindex(&self.values, &locations))
+    }
+}
@@ -21,7 +21,12 @@ where
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let str_index = formatting::pad_string_vector(&self.index.values);
-        let str_values = formatting::pad_string_vector(&self.values);
+        let str_values: Vec<String> = self.values
+            .iter()
+            .zip(self.null_flags())
+            .map(|(v, m)| if m { "Null".to_string() } else { v.to_string() })
+            .collect();
+        let str_values = formatting::pad_string_vector(&str_values);
 
         let mut result = vec![];
         for (i, v) in str_index.into_iter().zip(str_values.into_iter()) {
@@ -1,18 +1,20 @@
 
+use nullvec::prelude::Array;
 use num::{Zero, ToPrimitive};
-use std::cmp::Ord;
+use std::borrow::Cow;
 use std::ops::{Add, Sub, Div};
 use std::hash::Hash;
 
 use super::Series;
 use algos::computation::NanMinMax;
-use algos::grouper::Grouper;
-use groupby::GroupBy;
-use traits::{Apply, BasicAggregation, NumericAggregation, ComparisonAggregation};
+use error::Error;
+use frame::DataFrame;
+use groupby::{GroupBy, AggFunc};
+use traits::{RowIndex, Apply, BasicAggregation, NumericAggregation, ComparisonAggregation};
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Apply
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Apply
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<'v, 'i, V, I, G, W> Apply<'i, W> for GroupBy<'i, Series<'v, 'i, V, I>, G>
 where
@@ -21,8 +23,7 @@ where
     G: 'i
         + Clone
         + Eq
-        + Hash
-        + Ord,
+        + Hash,
     W: 'i + Clone,
 {
     type In = Series<'v, 'i, V, I>;
@@ -31,11 +32,10 @@ where
     type Out = Series<'i, 'i, W, G>;
 
     /// Apply passed function to each group
-    fn apply<'f>(&'i self, func: &'f Fn(&Self::In) -> Self::FOut) -> Self::Out {
-
-        let mut new_values: Vec<W> = Vec::with_capacity(self.grouper.len());
+    fn apply<'f>(&'i self, func: &'f dyn Fn(&Self::In) -> Self::FOut) -> Self::Out {
 
         let groups = self.groups();
+        let mut new_values: Vec<W> = Vec::with_capacity(groups.len());
         for g in &groups {
             let s = self.get_group(g);
             new_values.push(func(&s));
@@ -44,14 +44,94 @@ where
     }
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Aggregation
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Transform
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, V, I, G> GroupBy<'i, Series<'v, 'i, V, I>, G>
+where
+    V: 'v + Clone,
+    I: Clone + Eq + Hash,
+    G: 'i + Clone + Eq + Hash,
+{
+    /// Apply passed function to each group, and return the result which has
+    /// the same length and index as the original Series.
+    /// The function must return the same number of values as the group.
+    /// Null values and rows whose key is dropped as null are null in the result
+    pub fn transform<W>(&self, func: &dyn Fn(&Series<V, I>) -> Vec<W>) -> Series<'i, 'i, W, I>
+    where
+        W: Clone + Default,
+    {
+        let mut new_values: Vec<W> = vec![W::default(); self.data.len()];
+        let mut new_mask: Vec<bool> = self.data.null_flags();
+        let mut grouped: Vec<bool> = vec![false; self.data.len()];
+        for locs in self.locations() {
+            let transformed = func(&self.data.ilocs(locs));
+            assert!(transformed.len() == locs.len(), "Length mismatch!");
+            for (&loc, value) in locs.iter().zip(transformed) {
+                new_values[loc] = value;
+                grouped[loc] = true;
+            }
+        }
+        for (m, g) in new_mask.iter_mut().zip(grouped) {
+            *m = *m || !g;
+        }
+        Series::from_cow(Cow::Owned(new_values), self.data.index.clone()).set_mask(Some(new_mask))
+    }
+
+    /// Return rows of groups which satisfy passed predicate, keeping the original order
+    pub fn filter(&self, func: &dyn Fn(&Series<V, I>) -> bool) -> Series<'v, 'i, V, I> {
+        let mut locations: Vec<usize> = vec![];
+        for locs in self.locations() {
+            if func(&self.data.ilocs(locs)) {
+                locations.extend(locs);
+            }
+        }
+        locations.sort();
+        self.data.ilocs(&locations)
+    }
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Aggregation
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, V, I, G> GroupBy<'i, Series<'v, 'i, V, I>, G>
+where
+    V: Clone,
+    I: Clone + Eq + Hash,
+    G: 'i + Clone + Eq + Hash,
+    Array: From<Vec<V>>,
+{
+    /// Aggregate with multiple functions, result columns are labeled by function names.
+    /// Panics if try_agg returns Err
+    pub fn agg<'f>(&self, funcs: &[AggFunc<'f>]) -> DataFrame<'i, 'i, 'i, G, &'f str> {
+        match self.try_agg(funcs) {
+            Ok(result) => result,
+            Err(message) => panic!("{}", message),
+        }
+    }
+
+    /// Aggregate with multiple functions, Err if a function is not applicable
+    /// to the values
+    pub fn try_agg<'f>(&self, funcs: &[AggFunc<'f>]) -> Result<DataFrame<'i, 'i, 'i, G, &'f str>, Error> {
+        let groups = self.groups();
+        let locations = self.locations();
+
+        let values: Array = self.data.to_array();
+        let new_values: Vec<Array> = funcs
+            .iter()
+            .map(|f| f.aggregate(&values, &locations))
+            .collect::<Result<Vec<Array>, Error>>()?;
+        let new_columns: Vec<&str> = funcs.iter().map(|f| f.name()).collect();
+        Ok(DataFrame::from_vec(new_values, groups, new_columns))
+    }
+}
 
 impl<'v, 'i, V, I, G> BasicAggregation<'i> for GroupBy<'i, Series<'v, 'i, V, I>, G>
     where V: Clone + Zero + Add,
           I: Clone + Eq + Hash,
-          G: 'i + Clone + Eq + Hash + Ord
+          G: 'i + Clone + Eq + Hash
 {
     // result can have different lifetime
     // ToDo: use 'n lifetime for value
@@ -70,7 +150,7 @@ impl<'v, 'i, V, I, G> BasicAggregation<'i> for GroupBy<'i, Series<'v, 'i, V, I>,
 impl<'v, 'i, V, I, G> NumericAggregation<'i> for GroupBy<'i, Series<'v, 'i, V, I>, G>
     where V: Clone + Zero + Add + Sub + Div + ToPrimitive,
           I: Clone + Eq + Hash,
-          G: 'i + Clone + Eq + Hash + Ord
+          G: 'i + Clone + Eq + Hash
 {
     // result can have different lifetime
     // ToDo: use 'n lifetime for value
@@ -100,7 +180,7 @@ impl<'v, 'i, V, I, G> NumericAggregation<'i> for GroupBy<'i, Series<'v, 'i, V, I
 impl<'v, 'i, V, I, G> ComparisonAggregation<'i> for GroupBy<'i, Series<'v, 'i, V, I>, G>
     where V: Clone + NanMinMax<V>,
           I: Clone + Eq + Hash,
-          G: 'i + Clone + Eq + Hash + Ord
+          G: 'i + Clone + Eq + Hash
 {
     // result can have different lifetime
     // ToDo: use 'n lifetime for value
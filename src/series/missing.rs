@@ -0,0 +1,77 @@
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use nullvec::prelude::dev::algos::Indexing;
+
+use super::{Series, not};
+use algos::fill::{FillNA, Propagate};
+use algos::join::USIZE_MISSING;
+use traits::Slicer;
+
+// /////////////////////////////////////////////////////////////////////////////
+// Missing values
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone,
+    I: Clone + Eq + Hash,
+{
+    pub fn has_null(&self) -> bool {
+        self.mask.is_some()
+    }
+
+    /// Return bool Series which is true where the value is null
+    pub fn isnull(&self) -> Series<'i, 'i, bool, I> {
+        Series::from_cow(Cow::Owned(self.null_flags()), self.index.clone())
+    }
+
+    /// Return bool Series which is true where the value is not null
+    pub fn notnull(&self) -> Series<'i, 'i, bool, I> {
+        Series::from_cow(Cow::Owned(not(&self.null_flags())), self.index.clone())
+    }
+
+    /// Return Series without null values
+    pub fn dropna(&self) -> Self {
+        match self.mask {
+            Some(ref mask) => {
+                let flags = not(mask);
+                let new_values: Vec<V> = Indexing::blocs(&self.values, &flags);
+                Series::from_cow(Cow::Owned(new_values), Cow::Owned(self.index.blocs(&flags)))
+            }
+            None => self.clone(),
+        }
+    }
+
+    /// Fill null values with the given value, or by propagating valid values.
+    /// Nulls which have no valid value to propagate are kept as null.
+    pub fn fillna(&self, method: FillNA<V>) -> Self {
+        let mask = match self.mask {
+            Some(ref mask) => mask,
+            None => return self.clone(),
+        };
+        let indexer = match method {
+            FillNA::Value(value) => {
+                let new_values: Vec<V> = self.values
+                    .iter()
+                    .zip(mask.iter())
+                    .map(|(v, &m)| if m { value.clone() } else { v.clone() })
+                    .collect();
+                return Series::from_cow(Cow::Owned(new_values), self.index.clone());
+            }
+            FillNA::Ffill => Propagate::forward(mask, None),
+            FillNA::Bfill => Propagate::backward(mask, None),
+        };
+        let new_values: Vec<V> = indexer
+            .iter()
+            .enumerate()
+            .map(|(i, &loc)| if loc == USIZE_MISSING {
+                self.values[i].clone()
+            } else {
+                self.values[loc].clone()
+            })
+            .collect();
+        let new_mask: Vec<bool> = indexer.iter().map(|&loc| loc == USIZE_MISSING).collect();
+        Series::from_cow(Cow::Owned(new_values), self.index.clone()).set_mask(Some(new_mask))
+    }
+}
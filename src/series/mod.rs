@@ -4,17 +4,28 @@ use std::iter::FromIterator;
 use std::slice;
 use std::vec;
 
+use nullvec::prelude::Array;
 use nullvec::prelude::dev::algos::Indexing;
+use algos::join::{JoinOp, HashJoin, USIZE_MISSING};
+use block::Block;
+use error::Error;
+use frame::DataFrame;
 use indexer::Indexer;
 use groupby::GroupBy;
-use traits::{Slicer, IndexerIndex, RowIndex, Append, Apply};
+use traits::{Slicer, IndexerIndex, RowIndex, Append, Join, Apply};
 
 mod aggregation;
+mod compare;
 mod convert;
+mod duplicates;
+mod factorize;
 mod formatting;
 mod groupby;
+mod missing;
 mod ops;
+mod rolling;
 mod sort;
+mod transform;
 
 #[derive(Clone)]
 pub struct Series<'v, 'i, V, I>
@@ -24,11 +35,13 @@ where
 {
     pub values: Cow<'v, Vec<V>>,
     pub index: Cow<'i, Indexer<I>>,
+    /// whether each value is null, None if no value is null
+    pub mask: Option<Vec<bool>>,
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Indexing
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Indexing
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<'v, 'i, V, I> RowIndex<'i> for Series<'v, 'i, V, I>
 where
@@ -42,11 +55,20 @@ where
         self.values.len()
     }
 
+    /// Value at null position is the placeholder behind the mask, which has no
+    /// meaning. Check isnull or mask to distinguish it
     fn loc(&self, label: &Self::Key) -> Self::Row {
         let loc = self.index.get_loc(label);
         self.iloc(&loc)
     }
 
+    /// Value at null position is the placeholder, see loc
+    fn try_loc(&self, label: &Self::Key) -> Result<Self::Row, Error> {
+        let loc = self.index.try_get_loc(label)?;
+        Ok(self.iloc(&loc))
+    }
+
+    /// Value at null position is the placeholder, see loc
     fn iloc(&self, location: &usize) -> Self::Row {
         self.values[*location].clone()
     }
@@ -56,26 +78,28 @@ where
 
         let new_index = self.index.reindex(&locations);
         let new_values = unsafe { Indexing::reindex_unchecked(&self.values, &locations) };
-        Series::new(new_values, new_index)
+        let new_mask = self.mask
+            .as_ref()
+            .map(|x| unsafe { Indexing::reindex_unchecked(x, &locations) });
+        Series::new(new_values, new_index).set_mask(new_mask)
     }
 
     fn reindex_by_index(&self, locations: &[usize]) -> Self {
-        let new_index = self.index.reindex(locations);
-        let new_values = unsafe { Indexing::reindex_unchecked(&self.values, locations) };
-        Series::new(new_values, new_index)
+        self.take(locations)
     }
 
     /// Slice using given Vec<bool> (slice by Bool LOCationS)
     fn blocs(&self, flags: &[bool]) -> Self {
         let new_values: Vec<Self::Row> = Indexing::blocs(&self.values, flags);
         let new_index = self.index.blocs(flags);
-        Series::new(new_values, new_index)
+        let new_mask = self.mask.as_ref().map(|x| Indexing::blocs(x, flags));
+        Series::new(new_values, new_index).set_mask(new_mask)
     }
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Misc
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Misc
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<'v, 'i, V, I> Series<'v, 'i, V, I>
 where
@@ -88,6 +112,7 @@ where
         Series {
             values: Cow::Owned(values),
             index: Cow::Owned(index),
+            mask: None,
         }
     }
 
@@ -103,7 +128,29 @@ where
         Series {
             values: Cow::Owned(values),
             index: Cow::Owned(index),
+            mask: None,
+        }
+    }
+
+    /// Create Series, Err if values and index have different length
+    pub fn try_new<X>(values: Vec<V>, index: X) -> Result<Self, Error>
+    where
+        X: Into<Indexer<I>>,
+    {
+        let index: Indexer<I> = index.into();
+        if values.len() != index.len() {
+            return Err(Error::LengthMismatch(index.len(), values.len()));
         }
+        Ok(Series::new(values, index))
+    }
+
+    /// Create Series which has null values. Values where mask is true are
+    /// regarded as null, and their contents have no meaning
+    pub fn with_mask<X>(values: Vec<V>, index: X, mask: Vec<bool>) -> Self
+    where
+        X: Into<Indexer<I>>,
+    {
+        Series::new(values, index).set_mask(Some(mask))
     }
 
     pub fn from_cow(values: Cow<'v, Vec<V>>, index: Cow<'i, Indexer<I>>) -> Self {
@@ -111,26 +158,123 @@ where
         assert!(values.len() == index.len(), "Length mismatch!");
 
         Series {
-            values: values,
-            index: index,
+            values,
+            index,
+            mask: None,
         }
     }
 
-    fn assert_binop(&self, other: &Self) {
-        assert!(self.index == other.index, "index must be the same!");
+    /// Replace null mask. Mask which has no null is normalized to None
+    fn set_mask(mut self, mask: Option<Vec<bool>>) -> Self {
+        if let Some(ref mask) = mask {
+            assert!(self.values.len() == mask.len(), "Length mismatch!");
+        }
+        self.mask = match mask {
+            Some(ref mask) if !mask.contains(&true) => None,
+            mask => mask,
+        };
+        self
+    }
+
+    /// Take values, index and null mask at given locations
+    fn take(&self, locations: &[usize]) -> Self {
+        let new_index = self.index.reindex(locations);
+        let new_values = unsafe { Indexing::reindex_unchecked(&self.values, locations) };
+        let new_mask = self.mask
+            .as_ref()
+            .map(|x| unsafe { Indexing::reindex_unchecked(x, locations) });
+        Series::new(new_values, new_index).set_mask(new_mask)
+    }
+
+    /// Whether each value is null
+    fn null_flags(&self) -> Vec<bool> {
+        match self.mask {
+            Some(ref mask) => mask.clone(),
+            None => vec![false; self.values.len()],
+        }
     }
 
-    pub fn groupby<G>(&self, other: &[G]) -> GroupBy<Series<V, I>, G>
+    /// Values which are not null, borrowed if no value is null
+    // Aggregation functions take &Vec
+    #[allow(clippy::owned_cow)]
+    fn valid_values(&self) -> Cow<'_, Vec<V>> {
+        match self.mask {
+            Some(ref mask) => Cow::Owned(Indexing::blocs(&self.values, &not(mask))),
+            None => Cow::Borrowed(self.values.as_ref()),
+        }
+    }
+
+    /// Convert to Array, null values are kept as null
+    pub fn to_array(&self) -> Array
     where
-        G: Clone + Eq + Hash + Ord,
+        Array: From<Vec<V>>,
+    {
+        let values = Array::from(self.values.clone().into_owned());
+        match self.mask {
+            Some(ref mask) => {
+                let locations: Vec<usize> = mask.iter()
+                    .enumerate()
+                    .map(|(i, &m)| if m { USIZE_MISSING } else { i })
+                    .collect();
+                values.ilocs_forced(&locations)
+            }
+            None => values,
+        }
+    }
+
+    /// Align values to the union of both indexes using outer join. Returns
+    /// joined index, and values and null flags of each side. Values at
+    /// labels which don't exist in the side are null and have no meaning
+    fn align(&self, other: &Series<V, I>) -> (Indexer<I>, Aligned<V>, Aligned<V>) {
+        if self.index == other.index {
+            return (
+                self.index.clone().into_owned(),
+                (self.values.clone().into_owned(), self.null_flags()),
+                (other.values.clone().into_owned(), other.null_flags()),
+            );
+        }
+
+        let (new_index, lindexer, rindexer) =
+            HashJoin::outer(&self.index.values, &other.index.values);
+        let lflags = self.null_flags();
+        let rflags = other.null_flags();
+
+        let mut lvalues: Vec<V> = Vec::with_capacity(new_index.len());
+        let mut lnulls: Vec<bool> = Vec::with_capacity(new_index.len());
+        let mut rvalues: Vec<V> = Vec::with_capacity(new_index.len());
+        let mut rnulls: Vec<bool> = Vec::with_capacity(new_index.len());
+
+        for (&l, &r) in lindexer.iter().zip(&rindexer) {
+            // missing label takes the value of other side as placeholder
+            if l == USIZE_MISSING {
+                lvalues.push(other.values[r].clone());
+                lnulls.push(true);
+            } else {
+                lvalues.push(self.values[l].clone());
+                lnulls.push(lflags[l]);
+            }
+            if r == USIZE_MISSING {
+                rvalues.push(self.values[l].clone());
+                rnulls.push(true);
+            } else {
+                rvalues.push(other.values[r].clone());
+                rnulls.push(rflags[r]);
+            }
+        }
+        (Indexer::new(new_index), (lvalues, lnulls), (rvalues, rnulls))
+    }
+
+    pub fn groupby<'a, G>(&'a self, other: &[G]) -> GroupBy<'a, Series<'a, 'a, V, I>, G>
+    where
+        G: 'a + Clone + Eq + Hash + Ord,
     {
         GroupBy::new(self, other)
     }
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Append
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Append
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<'v, 'i, V, I> Append<'i> for Series<'v, 'i, V, I>
 where
@@ -143,13 +287,72 @@ where
         // clone COW (not values, then to_mut())
         new_values.append(&mut other.values.clone().to_mut());
         let new_index = self.index.append(&other.index);
-        Series::new(new_values, new_index)
+
+        let new_mask = if self.mask.is_none() && other.mask.is_none() {
+            None
+        } else {
+            let mut new_mask = self.null_flags();
+            new_mask.append(&mut other.null_flags());
+            Some(new_mask)
+        };
+        Series::new(new_values, new_index).set_mask(new_mask)
+    }
+
+    /// Err if values, index or mask of either side have different lengths
+    fn try_append<'o>(&'i self, other: &'o Self) -> Result<Self, Error> {
+        for s in &[self, other] {
+            let len = s.index.len();
+            if s.values.len() != len {
+                return Err(Error::LengthMismatch(len, s.values.len()));
+            }
+            if let Some(ref mask) = s.mask {
+                if mask.len() != len {
+                    return Err(Error::LengthMismatch(len, mask.len()));
+                }
+            }
+        }
+        Ok(self.append(other))
+    }
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Join
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, V, I> Join for Series<'v, 'i, V, I>
+where
+    V: Clone,
+    I: Clone + Eq + Hash,
+    Array: From<Vec<V>>,
+{
+    // joined values are stored as columns 0 (self) and 1 (other)
+    type Output = Block<'v, 'i, 'i, V, I, usize>;
+    type NullableOutput = DataFrame<'v, 'i, 'i, I, usize>;
+
+    fn join_inner(&self, other: &Self) -> Self::Output {
+        let left = Block::from_series(self.clone(), 0);
+        left.join_inner(&Block::from_series(other.clone(), 1))
+    }
+
+    fn join_left(&self, other: &Self) -> Self::NullableOutput {
+        let left = Block::from_series(self.clone(), 0);
+        left.join_left(&Block::from_series(other.clone(), 1))
+    }
+
+    fn join_right(&self, other: &Self) -> Self::NullableOutput {
+        let left = Block::from_series(self.clone(), 0);
+        left.join_right(&Block::from_series(other.clone(), 1))
+    }
+
+    fn join_outer(&self, other: &Self) -> Self::NullableOutput {
+        let left = Block::from_series(self.clone(), 0);
+        left.join_outer(&Block::from_series(other.clone(), 1))
     }
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Apply
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Apply
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<'v, 'i, V, I, R> Apply<'i, R> for Series<'v, 'i, V, I>
 where
@@ -160,28 +363,41 @@ where
     type FOut = R;
     type Out = R;
 
-    fn apply<'f>(&'i self, func: &'f Fn(&Self::In) -> Self::FOut) -> Self::Out {
+    fn apply<'f>(&'i self, func: &'f dyn Fn(&Self::In) -> Self::FOut) -> Self::Out {
         func(&self.values)
     }
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Eq
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Eq
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<'v, 'i, V, I> PartialEq for Series<'v, 'i, V, I>
 where
     V: Clone + PartialEq,
     I: Clone + Hash + Eq,
 {
+    /// Values at null locations are not compared
     fn eq(&self, other: &Self) -> bool {
-        (self.index.eq(&other.index)) && (self.values.eq(&other.values))
+        if !self.index.eq(&other.index) || self.mask != other.mask {
+            return false;
+        }
+        match self.mask {
+            Some(ref mask) => {
+                self.values
+                    .iter()
+                    .zip(other.values.iter())
+                    .zip(mask.iter())
+                    .all(|((l, r), &m)| m || l == r)
+            }
+            None => self.values.eq(&other.values),
+        }
     }
 }
 
-/// /////////////////////////////////////////////////////////////////////////////
-/// Iterator
-/// /////////////////////////////////////////////////////////////////////////////
+// /////////////////////////////////////////////////////////////////////////////
+// Iterator
+// /////////////////////////////////////////////////////////////////////////////
 
 impl<'v, 'i, V, I> IntoIterator for Series<'v, 'i, V, I>
 where
@@ -219,3 +435,10 @@ where
         Series::<V, usize>::from_vec(values)
     }
 }
+
+/// Values and null flags of aligned Series
+type Aligned<V> = (Vec<V>, Vec<bool>);
+
+fn not(flags: &[bool]) -> Vec<bool> {
+    flags.iter().map(|&x| !x).collect()
+}
@@ -12,56 +12,72 @@ macro_rules! define_numeric_op {
         impl<'v, 'i, V, I, O> $t<V> for Series<'v, 'i, V, I>
             where V: Clone + $t<Output=O>,
                   I: Clone + Eq + Hash,
-                  O: 'v + Clone {
+                  O: 'v + Clone + Default {
 
             type Output = Series<'v, 'i, O, I>;
             fn $m(self, _rhs: V) -> Self::Output {
                 // binary ops doesn't require value's ownership
-                let new_values: Vec<O> = Elemwise::broadcast_ro(self.values.as_ref(),
-                                                                _rhs, |x, y| x.$m(y));
+                let new_values: Vec<O> = match self.mask {
+                    Some(ref mask) => apply_valid(self.values.iter().cloned(), mask,
+                                                  |x| x.$m(_rhs.clone())),
+                    None => Elemwise::broadcast_ro(self.values.as_ref(), _rhs, |x, y| x.$m(y)),
+                };
                 // self is moved, pass index to new instance
                 Series::from_cow(Cow::Owned(new_values), self.index)
+                    .set_mask(self.mask.clone())
             }
         }
 
         impl<'v, 'i, 'r, V, I, O> $t<&'r V> for Series<'v, 'i, V, I>
             where V: Clone + $t<Output=O>,
                   I: Clone + Eq + Hash,
-                  O: 'v + Clone {
+                  O: 'v + Clone + Default {
 
             type Output = Series<'v, 'i, O, I>;
             fn $m(self, _rhs: &'r V) -> Self::Output {
-                let new_values: Vec<O> = Elemwise::broadcast_rr(self.values.as_ref(),
-                                                                _rhs, |x, y| x.$m(y));
+                let new_values: Vec<O> = match self.mask {
+                    Some(ref mask) => apply_valid(self.values.iter().cloned(), mask,
+                                                  |x| x.$m(_rhs.clone())),
+                    None => Elemwise::broadcast_rr(self.values.as_ref(), _rhs, |x, y| x.$m(y)),
+                };
                 Series::from_cow(Cow::Owned(new_values), self.index)
+                    .set_mask(self.mask.clone())
             }
         }
 
         impl<'v, 'i, 'l, V, I, O> $t<V> for &'l Series<'v, 'i, V, I>
             where V: Clone + $t<Output=O>,
                   I: Clone + Eq + Hash,
-                  O: 'l + Clone {
+                  O: 'l + Clone + Default {
 
             type Output = Series<'l, 'l, O, I>;
             fn $m(self, _rhs: V) -> Self::Output {
-                let new_values: Vec<O> = Elemwise::broadcast_ro(self.values.as_ref(),
-                                                                _rhs, |x, y| x.$m(y));
+                let new_values: Vec<O> = match self.mask {
+                    Some(ref mask) => apply_valid(self.values.iter().cloned(), mask,
+                                                  |x| x.$m(_rhs.clone())),
+                    None => Elemwise::broadcast_ro(self.values.as_ref(), _rhs, |x, y| x.$m(y)),
+                };
                 Series::from_cow(Cow::Owned(new_values),
                                  Cow::Borrowed(self.index.borrow()))
+                    .set_mask(self.mask.clone())
             }
         }
 
         impl<'v, 'i, 'l, 'r, V, I, O> $t<&'r V> for &'l Series<'v, 'i, V, I>
             where V: Clone + $t<Output=O>,
                   I: Clone + Eq + Hash,
-                  O: 'l + Clone {
+                  O: 'l + Clone + Default {
 
             type Output = Series<'l, 'l, O, I>;
             fn $m(self, _rhs: &'r V) -> Self::Output {
-                let new_values: Vec<O> = Elemwise::broadcast_rr(self.values.as_ref(),
-                                                                _rhs, |x, y| x.$m(y));
+                let new_values: Vec<O> = match self.mask {
+                    Some(ref mask) => apply_valid(self.values.iter().cloned(), mask,
+                                                  |x| x.$m(_rhs.clone())),
+                    None => Elemwise::broadcast_rr(self.values.as_ref(), _rhs, |x, y| x.$m(y)),
+                };
                 Series::from_cow(Cow::Owned(new_values),
                                  Cow::Borrowed(self.index.borrow()))
+                    .set_mask(self.mask.clone())
             }
         }
 
@@ -69,15 +85,23 @@ macro_rules! define_numeric_op {
         impl<'lv, 'rv, 'li, 'ri, V, I, O> $t<Series<'rv, 'ri, V, I>> for Series<'lv, 'li, V, I>
             where V: Clone + $t<Output=O>,
                   I: Clone + Eq + Hash,
-                  O: 'lv + Clone {
+                  O: 'lv + Clone + Default {
 
             type Output = Series<'lv, 'li, O, I>;
             fn $m(self, _rhs: Series<V, I>) -> Self::Output {
-                self.assert_binop(&_rhs);
-                let new_values: Vec<O> = Elemwise::elemwise_oo(self.values.into_owned(),
-                                                               _rhs.values.into_owned(),
-                                                               |x, y| x.$m(y));
+                if self.index != _rhs.index {
+                    return apply_aligned(&self, &_rhs, None, |x, y| x.$m(y));
+                }
+                let new_mask = merge_masks(&self.mask, &_rhs.mask);
+                let new_values: Vec<O> = match new_mask {
+                    Some(ref mask) => {
+                        let values = self.values.iter().cloned().zip(_rhs.values.iter().cloned());
+                        apply_valid(values, mask, |(x, y)| x.$m(y))
+                    }
+                    None => Elemwise::elemwise_oo(self.values.into_owned(), _rhs.values.into_owned(), |x, y| x.$m(y)),
+                };
                 Series::from_cow(Cow::Owned(new_values), self.index)
+                    .set_mask(new_mask)
             }
         }
 
@@ -85,15 +109,23 @@ macro_rules! define_numeric_op {
             for Series<'lv, 'li, V, I>
             where V: Clone + $t<Output=O>,
                   I: Clone + Eq + Hash,
-                  O: 'lv + Clone {
+                  O: 'lv + Clone + Default {
 
             type Output = Series<'lv, 'li, O, I>;
             fn $m(self, _rhs: &'r Series<V, I>) -> Self::Output {
-                self.assert_binop(&_rhs);
-                let new_values: Vec<O> = Elemwise::elemwise_or(self.values.into_owned(),
-                                                               &_rhs.values.as_ref(),
-                                                               |x, y| x.$m(y));
+                if self.index != _rhs.index {
+                    return apply_aligned(&self, &_rhs, None, |x, y| x.$m(y));
+                }
+                let new_mask = merge_masks(&self.mask, &_rhs.mask);
+                let new_values: Vec<O> = match new_mask {
+                    Some(ref mask) => {
+                        let values = self.values.iter().cloned().zip(_rhs.values.iter().cloned());
+                        apply_valid(values, mask, |(x, y)| x.$m(y))
+                    }
+                    None => Elemwise::elemwise_or(self.values.into_owned(), &_rhs.values.as_ref(), |x, y| x.$m(y)),
+                };
                 Series::from_cow(Cow::Owned(new_values), self.index)
+                    .set_mask(new_mask)
             }
         }
 
@@ -101,18 +133,24 @@ macro_rules! define_numeric_op {
             for &'l Series<'lv, 'li, V, I>
             where V: Clone + $t<Output=O>,
                   I: Clone + Eq + Hash,
-                  O: 'l + Clone {
+                  O: 'l + Clone + Default {
             // cannot use 'n lifetime for associated dtype (uncostrained)
             type Output = Series<'l, 'l, O, I>;
             fn $m(self, _rhs: Series<V, I>) -> Self::Output {
-                self.assert_binop(&_rhs);
-
-                // ToDo: match with Cow::Owned / Borrowed
-                let new_values: Vec<O> = Elemwise::elemwise_ro(&self.values.as_ref(),
-                                                               _rhs.values.into_owned(),
-                                                               |x, y| x.$m(y));
+                if self.index != _rhs.index {
+                    return apply_aligned(&self, &_rhs, None, |x, y| x.$m(y));
+                }
+                let new_mask = merge_masks(&self.mask, &_rhs.mask);
+                let new_values: Vec<O> = match new_mask {
+                    Some(ref mask) => {
+                        let values = self.values.iter().cloned().zip(_rhs.values.iter().cloned());
+                        apply_valid(values, mask, |(x, y)| x.$m(y))
+                    }
+                    None => Elemwise::elemwise_ro(&self.values.as_ref(), _rhs.values.into_owned(), |x, y| x.$m(y)),
+                };
                 Series::from_cow(Cow::Owned(new_values),
                                  Cow::Borrowed(self.index.borrow()))
+                    .set_mask(new_mask)
             }
         }
 
@@ -121,19 +159,104 @@ macro_rules! define_numeric_op {
 
             where V: Clone + $t<Output=O>,
                   I: Clone + Eq + Hash,
-                  O: 'l + Clone {
+                  O: 'l + Clone + Default {
 
             type Output = Series<'l, 'l, O, I>;
             fn $m(self, _rhs: &'r Series<V, I>) -> Self::Output {
-                self.assert_binop(&_rhs);
-                let new_values: Vec<O> = Elemwise::elemwise_rr(&self.values,
-                                                               &_rhs.values,
-                                                               |x, y| x.$m(y));
+                if self.index != _rhs.index {
+                    return apply_aligned(&self, &_rhs, None, |x, y| x.$m(y));
+                }
+                let new_mask = merge_masks(&self.mask, &_rhs.mask);
+                let new_values: Vec<O> = match new_mask {
+                    Some(ref mask) => {
+                        let values = self.values.iter().cloned().zip(_rhs.values.iter().cloned());
+                        apply_valid(values, mask, |(x, y)| x.$m(y))
+                    }
+                    None => Elemwise::elemwise_rr(&self.values, &_rhs.values, |x, y| x.$m(y)),
+                };
                 Series::from_cow(Cow::Owned(new_values),
                                  Cow::Borrowed(self.index.borrow()))
+                    .set_mask(new_mask)
+            }
+        }
+    }
+}
+
+macro_rules! define_aligned_op {
+    ($t:ident, $m:ident, $aligned:ident) => {
+        impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+            where V: Clone,
+                  I: Clone + Eq + Hash {
+
+            /// Perform element-wise operation aligning values by index labels.
+            /// If fill_value is given, it is used in place of the value which is
+            /// null or missing in either side. Result is null where both are null
+            pub fn $aligned<'o, O>(&self, other: &Series<V, I>, fill_value: Option<V>)
+                -> Series<'o, 'o, O, I>
+                where V: $t<Output=O>,
+                      I: 'o,
+                      O: 'o + Clone + Default {
+
+                apply_aligned(self, other, fill_value, |x, y| x.$m(y))
+            }
+        }
+    }
+}
+
+/// Apply func to values aligned by index labels. Labels which exist only in one
+/// side are null in the result unless fill_value is given. func is applied only
+/// where both sides are valid
+fn apply_aligned<'a, 'b, V, I, O, F>(left: &Series<V, I>,
+                                     right: &Series<V, I>,
+                                     fill_value: Option<V>,
+                                     func: F)
+                                     -> Series<'a, 'b, O, I>
+    where V: Clone,
+          I: 'b + Clone + Eq + Hash,
+          O: 'a + Clone + Default,
+          F: Fn(V, V) -> O {
+
+    let (new_index, (mut lvalues, mut lnulls), (mut rvalues, mut rnulls)) = left.align(right);
+
+    if let Some(ref fill) = fill_value {
+        for ((lv, ln), (rv, rn)) in lvalues.iter_mut().zip(lnulls.iter_mut())
+            .zip(rvalues.iter_mut().zip(rnulls.iter_mut())) {
+            if *ln && !*rn {
+                *lv = fill.clone();
+                *ln = false;
+            } else if *rn && !*ln {
+                *rv = fill.clone();
+                *rn = false;
             }
         }
     }
+    let new_mask: Vec<bool> = lnulls.iter().zip(&rnulls).map(|(&x, &y)| x | y).collect();
+    let new_values: Vec<O> = apply_valid(lvalues.into_iter().zip(rvalues), &new_mask,
+                                         |(x, y)| func(x, y));
+    Series::from_cow(Cow::Owned(new_values), Cow::Owned(new_index)).set_mask(Some(new_mask))
+}
+
+/// Apply func to values which are not null. func is never called with the
+/// placeholder behind null, and the result is filled with default instead
+fn apply_valid<T, O, F>(values: T, nulls: &[bool], func: F) -> Vec<O>
+    where T: Iterator,
+          O: Default,
+          F: Fn(T::Item) -> O {
+
+    values
+        .zip(nulls.iter())
+        .map(|(v, &n)| if n { O::default() } else { func(v) })
+        .collect()
+}
+
+/// Result is null where either value is null
+fn merge_masks(left: &Option<Vec<bool>>, right: &Option<Vec<bool>>) -> Option<Vec<bool>> {
+    match (left.as_ref(), right.as_ref()) {
+        (Some(l), Some(r)) => Some(l.iter().zip(r.iter()).map(|(&x, &y)| x | y).collect()),
+        (Some(l), None) => Some(l.clone()),
+        (None, Some(r)) => Some(r.clone()),
+        (None, None) => None,
+    }
 }
 
 define_numeric_op!(Add, add);
@@ -145,6 +268,11 @@ define_numeric_op!(BitAnd, bitand);
 define_numeric_op!(BitOr, bitor);
 define_numeric_op!(BitXor, bitxor);
 
+define_aligned_op!(Add, add, add_aligned);
+define_aligned_op!(Mul, mul, mul_aligned);
+define_aligned_op!(Sub, sub, sub_aligned);
+define_aligned_op!(Div, div, div_aligned);
+
 #[cfg(test)]
 mod tests {
 
@@ -417,4 +545,61 @@ mod tests {
         let exp = Series::<bool, i64>::new(vec![true, true, false], vec![10, 20, 30]);
         assert_eq!(result, exp);
     }
+
+    #[test]
+    fn test_series_ops_i64_elemwise_align() {
+        let exp = Series::<i64, i64>::with_mask(vec![7, 0, 8, 0],
+                                                vec![10, 20, 30, 40],
+                                                vec![false, true, false, true]);
+
+        let s = Series::<i64, i64>::new(vec![1, 2, 3], vec![10, 20, 30]);
+        let r = Series::<i64, i64>::new(vec![5, 6, 7], vec![30, 10, 40]);
+        let result = &s + &r;
+        assert_eq!(result, exp);
+
+        let result = &s + r;
+        assert_eq!(result, exp);
+
+        let r = Series::<i64, i64>::new(vec![5, 6, 7], vec![30, 10, 40]);
+        let result = s.clone() + &r;
+        assert_eq!(result, exp);
+
+        let result = s + r;
+        assert_eq!(result, exp);
+    }
+
+    #[test]
+    fn test_series_ops_f64_elemwise_align_null() {
+        let s = Series::<f64, i64>::with_mask(vec![1., 2., 3.],
+                                              vec![10, 20, 30],
+                                              vec![false, true, false]);
+        let r = Series::<f64, i64>::new(vec![2., 4.], vec![30, 20]);
+        let result = s * r;
+        let exp = Series::<f64, i64>::with_mask(vec![0., 0., 6.],
+                                                vec![10, 20, 30],
+                                                vec![true, true, false]);
+        assert_eq!(result, exp);
+    }
+
+    #[test]
+    fn test_series_ops_i64_null_skipped() {
+        // division by placeholder behind null must not panic
+        let s = Series::<i64, i64>::new(vec![1, 2, 3], vec![10, 20, 30]);
+        let r = Series::<i64, i64>::with_mask(vec![1, 0, 1], vec![10, 20, 30],
+                                              vec![false, true, false]);
+        let exp = Series::<i64, i64>::with_mask(vec![1, 0, 3], vec![10, 20, 30],
+                                                vec![false, true, false]);
+        assert_eq!(&s / &r, exp);
+        assert_eq!(&s / r.clone(), exp);
+        assert_eq!(s.clone() / &r, exp);
+        assert_eq!(s.clone() % r.clone(), Series::with_mask(vec![0, 0, 0], vec![10, 20, 30],
+                                                            vec![false, true, false]));
+
+        // overflow by placeholder behind null must not panic
+        let s = Series::<i64, i64>::with_mask(vec![4, i64::MIN], vec![10, 20],
+                                              vec![false, true]);
+        let exp = Series::<i64, i64>::with_mask(vec![-4, 0], vec![10, 20], vec![false, true]);
+        assert_eq!(&s / -1, exp);
+        assert_eq!(s / -1, exp);
+    }
 }
@@ -0,0 +1,174 @@
+use num::ToPrimitive;
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, NullVec};
+
+use super::Series;
+use frame::Column;
+use algos::window::Decay;
+use rolling::{Rolling, Ewm};
+use traits::{Apply, BasicAggregation, NumericAggregation, ComparisonAggregation};
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + ToPrimitive,
+    I: Clone + Eq + Hash,
+{
+    /// Provide rolling window calculations. Results are labeled at the right edge
+    /// of each window, or at the center if center is true
+    pub fn rolling(&self, window: usize, min_periods: usize, center: bool) -> Rolling<'_, Series<'_, '_, V, I>> {
+        Rolling::new(self, window, min_periods, center)
+    }
+
+    /// Provide expanding window calculations, each result covers all preceding values
+    pub fn expanding(&self, min_periods: usize) -> Rolling<'_, Series<'_, '_, V, I>> {
+        Rolling::expanding(self, self.values.len(), min_periods)
+    }
+
+    /// Provide exponentially weighted calculations
+    pub fn ewm(&self, decay: Decay, adjust: bool) -> Ewm<'_, Series<'_, '_, V, I>> {
+        Ewm::new(self, decay, adjust)
+    }
+}
+
+pub fn to_options<V, I>(series: &Series<V, I>) -> Vec<Option<f64>>
+where
+    V: Clone + ToPrimitive,
+    I: Clone + Eq + Hash,
+{
+    let flags = series.null_flags();
+    series
+        .values
+        .iter()
+        .zip(flags.iter())
+        .map(|(x, &m)| if m {
+            None
+        } else {
+            x.to_f64().and_then(|v| if v.is_nan() { None } else { Some(v) })
+        })
+        .collect()
+}
+
+pub fn wrap<'v, 'i, V, I>(series: &Series<'v, 'i, V, I>, values: NullVec<f64>) -> Column<'i, 'i, I>
+where
+    V: Clone,
+    I: Clone + Eq + Hash,
+{
+    Column::from_cow(
+        Cow::Owned(Array::Float64Array(values)),
+        series.index.clone(),
+    )
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Apply
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'a, 'v, 'i, V, I> Apply<'a, f64> for Rolling<'a, Series<'v, 'i, V, I>>
+where
+    V: Clone + ToPrimitive,
+    I: Clone + Eq + Hash,
+{
+    type In = Vec<f64>;
+    type FOut = f64;
+    type Out = Column<'i, 'i, I>;
+
+    /// Apply passed function to non-null values in each window
+    fn apply<'f>(&'a self, func: &'f dyn Fn(&Self::In) -> Self::FOut) -> Self::Out {
+        wrap(self.data, self.window.apply(&to_options(self.data), func))
+    }
+}
+
+// /////////////////////////////////////////////////////////////////////////////
+// Aggregation
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'a, 'v, 'i, V, I> BasicAggregation<'a> for Rolling<'a, Series<'v, 'i, V, I>>
+where
+    V: Clone + ToPrimitive,
+    I: Clone + Eq + Hash,
+{
+    type Kept = Column<'i, 'i, I>;
+    type Counted = Column<'i, 'i, I>;
+
+    fn sum(&'a self) -> Self::Kept {
+        wrap(self.data, self.window.sum(&to_options(self.data)))
+    }
+
+    fn count(&'a self) -> Self::Counted {
+        wrap(self.data, self.window.count(&to_options(self.data)))
+    }
+}
+
+impl<'a, 'v, 'i, V, I> NumericAggregation<'a> for Rolling<'a, Series<'v, 'i, V, I>>
+where
+    V: Clone + ToPrimitive,
+    I: Clone + Eq + Hash,
+{
+    type Coerced = Column<'i, 'i, I>;
+
+    fn mean(&'a self) -> Self::Coerced {
+        wrap(self.data, self.window.mean(&to_options(self.data)))
+    }
+
+    fn var(&'a self) -> Self::Coerced {
+        wrap(self.data, self.window.var(&to_options(self.data), 0))
+    }
+
+    fn unbiased_var(&'a self) -> Self::Coerced {
+        wrap(self.data, self.window.var(&to_options(self.data), 1))
+    }
+
+    fn std(&'a self) -> Self::Coerced {
+        wrap(self.data, self.window.std(&to_options(self.data), 0))
+    }
+
+    fn unbiased_std(&'a self) -> Self::Coerced {
+        wrap(self.data, self.window.std(&to_options(self.data), 1))
+    }
+}
+
+impl<'a, 'v, 'i, V, I> ComparisonAggregation<'a> for Rolling<'a, Series<'v, 'i, V, I>>
+where
+    V: Clone + ToPrimitive,
+    I: Clone + Eq + Hash,
+{
+    type Kept = Column<'i, 'i, I>;
+
+    fn min(&'a self) -> Self::Kept {
+        wrap(self.data, self.window.min(&to_options(self.data)))
+    }
+
+    fn max(&'a self) -> Self::Kept {
+        wrap(self.data, self.window.max(&to_options(self.data)))
+    }
+}
+
+impl<'a, 'v, 'i, V, I> NumericAggregation<'a> for Ewm<'a, Series<'v, 'i, V, I>>
+where
+    V: Clone + ToPrimitive,
+    I: Clone + Eq + Hash,
+{
+    type Coerced = Column<'i, 'i, I>;
+
+    fn mean(&'a self) -> Self::Coerced {
+        wrap(self.data, self.weight.mean(&to_options(self.data)))
+    }
+
+    fn var(&'a self) -> Self::Coerced {
+        wrap(self.data, self.weight.var(&to_options(self.data), true))
+    }
+
+    fn unbiased_var(&'a self) -> Self::Coerced {
+        wrap(self.data, self.weight.var(&to_options(self.data), false))
+    }
+
+    fn std(&'a self) -> Self::Coerced {
+        wrap(self.data, self.weight.std(&to_options(self.data), true))
+    }
+
+    fn unbiased_std(&'a self) -> Self::Coerced {
+        wrap(self.data, self.weight.std(&to_options(self.data), false))
+    }
+}
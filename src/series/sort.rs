@@ -1,10 +1,8 @@
+use std::cmp::Ordering;
 use std::hash::Hash;
 
 use super::Series;
-use nullvec::prelude::dev::algos::Indexing;
-use nullvec::prelude::dev::algos::Sorter;
-use indexer::Indexer;
-use traits::Slicer;
+use algos::sort::NaPosition;
 
 //**********************************************
 //*Soat
@@ -16,9 +14,8 @@ where
     I: Clone + Eq + Hash + Ord,
 {
     pub fn sort_index(&self) -> Self {
-        let (indexer, sorted) = self.index.argsort();
-        let new_values = Indexing::reindex(&self.values, &indexer);
-        Series::new(new_values, sorted)
+        let (indexer, _) = self.index.argsort();
+        self.take(&indexer)
     }
 }
 
@@ -27,17 +24,115 @@ where
     V: Clone + Ord,
     I: Clone + Eq + Hash,
 {
+    /// Sort values in ascending order, null is placed last
     pub fn sort_values(&self) -> Self {
-        let (indexer, sorted) = Sorter::argsort(&self.values);
-        let index: Indexer<I> = self.index.reindex(&indexer);
-        Series::new(sorted, index)
+        let nulls = self.null_flags();
+        let mut indexer: Vec<usize> = (0..self.values.len()).collect();
+        indexer.sort_by(|&l, &r| {
+            nulls[l].cmp(&nulls[r]).then_with(|| self.values[l].cmp(&self.values[r]))
+        });
+        self.take(&indexer)
+    }
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + PartialOrd,
+    I: Clone + Eq + Hash,
+{
+    /// Return locations which sort values. The sort is stable, and null or values
+    /// which are not comparable with itself (NaN) are placed according to na_position
+    pub fn argsort(&self, ascending: bool, na_position: NaPosition) -> Vec<usize> {
+        let nas = self.na_flags();
+        let mut indexer: Vec<usize> = (0..self.values.len()).collect();
+        indexer.sort_by(|&l, &r| {
+            compare_values((&self.values[l], nas[l]),
+                           (&self.values[r], nas[r]),
+                           ascending,
+                           na_position)
+        });
+        indexer
+    }
+
+    /// Sort values which are not necessarily Ord, such as float
+    pub fn sort_values_with(&self, ascending: bool, na_position: NaPosition) -> Self {
+        let indexer = self.argsort(ascending, na_position);
+        self.take(&indexer)
+    }
+
+    /// Return n largest values in descending order, null and NaN are excluded
+    pub fn nlargest(&self, n: usize) -> Self {
+        self.select_n(n, false)
+    }
+
+    /// Return n smallest values in ascending order, null and NaN are excluded
+    pub fn nsmallest(&self, n: usize) -> Self {
+        self.select_n(n, true)
+    }
+
+    fn select_n(&self, n: usize, ascending: bool) -> Self {
+        let values: &Vec<V> = &self.values;
+        let nas = self.na_flags();
+        let mut indexer: Vec<usize> = (0..values.len()).filter(|&i| !nas[i]).collect();
+
+        // ties are kept in the original order
+        let compare = |l: &usize, r: &usize| {
+            compare_values((&values[*l], false), (&values[*r], false), ascending, NaPosition::Last)
+                .then(l.cmp(r))
+        };
+        if n == 0 {
+            indexer.clear();
+        } else if n < indexer.len() {
+            // only select n values, no need to sort all
+            indexer.select_nth_unstable_by(n - 1, &compare);
+            indexer.truncate(n);
+        }
+        indexer.sort_by(&compare);
+        self.take(&indexer)
+    }
+
+    /// Whether each value is null or NaN
+    fn na_flags(&self) -> Vec<bool> {
+        self.null_flags()
+            .into_iter()
+            .zip(self.values.iter())
+            .map(|(n, v)| n || is_nan(v))
+            .collect()
+    }
+}
+
+fn is_nan<V: PartialOrd>(value: &V) -> bool {
+    value.partial_cmp(value).is_none()
+}
+
+/// Compare values with flags whether each is null or NaN
+fn compare_values<V: PartialOrd>(
+    (left, left_na): (&V, bool),
+    (right, right_na): (&V, bool),
+    ascending: bool,
+    na_position: NaPosition,
+) -> Ordering {
+    let nulls_first = na_position == NaPosition::First;
+    match (left_na, right_na) {
+        (true, true) => Ordering::Equal,
+        (true, false) => if nulls_first { Ordering::Less } else { Ordering::Greater },
+        (false, true) => if nulls_first { Ordering::Greater } else { Ordering::Less },
+        (false, false) => {
+            // never be None
+            let ord = left.partial_cmp(right).unwrap();
+            if ascending { ord } else { ord.reverse() }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
 
+    use std::f64;
+
     use super::super::Series;
+    use algos::sort::NaPosition;
+    use indexer::Indexer;
 
     #[test]
     fn test_sort_index_int() {
@@ -55,4 +150,60 @@ mod tests {
         let exp = Series::new(vec![3, 2, 4, 1], vec!["a", "b", "c", "d"]);
         assert_eq!(sorted, exp);
     }
+
+    #[test]
+    fn test_sort_null() {
+        let s = Series::with_mask(vec![3, 0, 1, 2], vec!["a", "b", "c", "d"],
+                                  vec![false, true, false, false]);
+
+        let exp = Series::with_mask(vec![1, 2, 3, 0], vec!["c", "d", "a", "b"],
+                                    vec![false, false, false, true]);
+        assert_eq!(s.sort_values(), exp);
+
+        assert_eq!(s.sort_index(), s);
+
+        let exp = Series::with_mask(vec![0, 3, 2, 1], vec!["b", "a", "d", "c"],
+                                    vec![true, false, false, false]);
+        assert_eq!(s.sort_values_with(false, NaPosition::First), exp);
+
+        let exp = Series::new(vec![3, 2], vec!["a", "d"]);
+        assert_eq!(s.nlargest(2), exp);
+        let exp = Series::new(vec![1, 2, 3], vec!["c", "d", "a"]);
+        assert_eq!(s.nsmallest(5), exp);
+
+        let s = Series::with_mask(vec![3, 0, 1], vec![30, 20, 10], vec![false, true, false]);
+        let exp = Series::with_mask(vec![1, 0, 3], vec![10, 20, 30], vec![false, true, false]);
+        assert_eq!(s.sort_index(), exp);
+    }
+
+    #[test]
+    fn test_sort_values_float() {
+        let s = Series::new(vec![2.5, f64::NAN, -1., 3., 2.5], vec!["a", "b", "c", "d", "e"]);
+
+        let sorted = s.sort_values_with(true, NaPosition::Last);
+        assert_eq!(sorted.index.as_ref(), &Indexer::new(vec!["c", "a", "e", "d", "b"]));
+        assert_eq!(&sorted.values[..4], &[-1., 2.5, 2.5, 3.]);
+        assert!(sorted.values[4].is_nan());
+
+        let sorted = s.sort_values_with(false, NaPosition::First);
+        assert_eq!(sorted.index.as_ref(), &Indexer::new(vec!["b", "d", "a", "e", "c"]));
+
+        assert_eq!(s.argsort(true, NaPosition::First), vec![1, 2, 0, 4, 3]);
+        assert_eq!(s.argsort(false, NaPosition::Last), vec![3, 0, 4, 2, 1]);
+    }
+
+    #[test]
+    fn test_nlargest_nsmallest() {
+        let s = Series::new(vec![3., f64::NAN, 5., 1., 5., 2.], vec![1, 2, 3, 4, 5, 6]);
+
+        let exp = Series::new(vec![5., 5., 3.], vec![3, 5, 1]);
+        assert_eq!(s.nlargest(3), exp);
+
+        let exp = Series::new(vec![1., 2.], vec![4, 6]);
+        assert_eq!(s.nsmallest(2), exp);
+
+        // NaN is excluded
+        assert_eq!(s.nsmallest(10).values.len(), 5);
+        assert_eq!(s.nlargest(0).values.len(), 0);
+    }
 }
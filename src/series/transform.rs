@@ -0,0 +1,89 @@
+use num::ToPrimitive;
+use std::borrow::Cow;
+use std::hash::Hash;
+
+use nullvec::prelude::{Array, NullVec};
+
+use super::Series;
+use super::rolling::{to_options, wrap};
+use algos::transform::{Accumulate, Cumulative, Shift};
+use frame::Column;
+use traits::Slicer;
+
+// /////////////////////////////////////////////////////////////////////////////
+// Transform
+// /////////////////////////////////////////////////////////////////////////////
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + ToPrimitive + Accumulate,
+    I: Clone + Eq + Hash,
+    Array: From<NullVec<V>>,
+{
+    /// Cumulative sum, NaN is skipped and kept as null.
+    /// The dtype is kept, integer overflow is null.
+    pub fn cumsum(&self) -> Column<'i, 'i, I> {
+        self.accumulate(&Cumulative::sum)
+    }
+
+    /// Cumulative product, NaN is skipped and kept as null.
+    /// The dtype is kept, integer overflow is null.
+    pub fn cumprod(&self) -> Column<'i, 'i, I> {
+        self.accumulate(&Cumulative::prod)
+    }
+
+    /// Cumulative maximum, NaN is skipped and kept as null
+    pub fn cummax(&self) -> Column<'i, 'i, I> {
+        self.accumulate(&Cumulative::max)
+    }
+
+    /// Cumulative minimum, NaN is skipped and kept as null
+    pub fn cummin(&self) -> Column<'i, 'i, I> {
+        self.accumulate(&Cumulative::min)
+    }
+
+    fn accumulate(&self, func: &dyn Fn(&[Option<V>]) -> NullVec<V>) -> Column<'i, 'i, I> {
+        let values: Vec<Option<V>> = self.values
+            .iter()
+            .zip(self.null_flags())
+            .map(|(x, m)| match x.to_f64() {
+                Some(v) if !m && !v.is_nan() => Some(*x),
+                _ => None,
+            })
+            .collect();
+        Column::from_cow(Cow::Owned(func(&values).into()), self.index.clone())
+    }
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone + ToPrimitive,
+    I: Clone + Eq + Hash,
+{
+    /// Difference from the value periods before.
+    /// Negative periods compare with the value periods after.
+    pub fn diff(&self, periods: isize) -> Column<'i, 'i, I> {
+        wrap(self, Shift::diff(&to_options(self), periods))
+    }
+
+    /// Ratio of change from the value periods before.
+    /// Negative periods compare with the value periods after.
+    pub fn pct_change(&self, periods: isize) -> Column<'i, 'i, I> {
+        wrap(self, Shift::pct_change(&to_options(self), periods))
+    }
+}
+
+impl<'v, 'i, V, I> Series<'v, 'i, V, I>
+where
+    V: Clone,
+    I: Clone + Eq + Hash,
+    Array: From<Vec<V>>,
+{
+    /// Shift values by periods keeping the index. Positions which have
+    /// no source value are filled with null.
+    pub fn shift(&self, periods: isize) -> Column<'i, 'i, I> {
+        let indexer = Shift::indexer(self.values.len(), periods);
+        let values = self.to_array().ilocs_forced(&indexer);
+        Column::from_cow(Cow::Owned(values), self.index.clone())
+    }
+}
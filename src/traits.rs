@@ -1,5 +1,11 @@
 //! Common Traits
 
+use std::slice;
+
+use nullvec::prelude::Array;
+
+use error::Error;
+
 /// Indexing methods for non-labeled Array / Indexer
 pub use nullvec::prelude::Slicer;
 
@@ -8,14 +14,40 @@ pub trait IndexerIndex: Slicer {
     type Key;
 
     fn contains(&self, label: &Self::Key) -> bool;
+    fn is_unique(&self) -> bool;
     fn push(&mut self, label: Self::Key);
     fn get_loc(&self, label: &Self::Key) -> usize;
+    /// Return label location, Err if label doesn't exist or is duplicated
+    fn try_get_loc(&self, label: &Self::Key) -> Result<usize, Error> {
+        let locs = self.try_get_all_locs(label)?;
+        match locs.len() {
+            1 => Ok(locs[0]),
+            _ => Err(Error::DuplicatedLabel),
+        }
+    }
+    fn get_all_locs(&self, label: &Self::Key) -> Vec<usize>;
+    /// Return all label locations, Err if label doesn't exist
+    fn try_get_all_locs(&self, label: &Self::Key) -> Result<Vec<usize>, Error>;
     fn get_locs(&self, labels: &[Self::Key]) -> Vec<usize>;
 
     // temp
     fn init_state(&self);
 }
 
+/// Primitive types which can be extracted from Array
+pub trait ArrayElement: Sized + Clone {
+    /// dtype name, the same as Array.dtype()
+    fn dtype() -> String;
+
+    /// Convert Array to Vec, Err if dtype is different or Array contains Null
+    /// which cannot be represented by the type
+    fn from_array(values: &Array) -> Result<Vec<Self>, Error>;
+
+    /// Convert Array to Vec and null mask, Err if dtype is different.
+    /// Mask is None if Array has no Null
+    fn from_nullable_array(values: &Array) -> Result<(Vec<Self>, Option<Vec<bool>>), Error>;
+}
+
 /// Indexing methods for Index(Row)
 pub trait RowIndex<'s>: Sized {
     // 's: lifetime of myself
@@ -41,9 +73,19 @@ pub trait RowIndex<'s>: Sized {
 
     // selection
 
-    /// Get a single value corresponding to given label (slice by LOCation)
+    /// Get a single value corresponding to given label (slice by LOCation).
+    /// Panics if label is duplicated, use loc_all to get all the matches
     fn loc<'l>(&'s self, label: &'l Self::Key) -> Self::Row;
 
+    /// Get all values corresponding to given label, which may be duplicated
+    fn loc_all<'l>(&'s self, label: &'l Self::Key) -> Self {
+        self.locs(slice::from_ref(label))
+    }
+
+    /// Get a single value corresponding to given label, Err if label doesn't exist
+    /// or is duplicated
+    fn try_loc<'l>(&'s self, label: &'l Self::Key) -> Result<Self::Row, Error>;
+
     /// Get a single value corresponding to given index (slice by Index LOCation)
     fn iloc<'l>(&'s self, location: &'l usize) -> Self::Row;
 
@@ -87,16 +129,30 @@ pub trait ColIndex<'s>: Sized {
 /// Concatenate along row
 pub trait Append<'s>: Sized {
     fn append<'o>(&'s self, other: &'o Self) -> Self;
+
+    /// Append other, Err instead of panic if it cannot be appended
+    fn try_append<'o>(&'s self, other: &'o Self) -> Result<Self, Error>;
 }
 
 /// Concatenate along columns
 pub trait Concatenation<'s>: Sized {
     fn concat<'o>(&'s self, other: &'o Self) -> Self;
+
+    /// Concatenate other, Err instead of panic if it cannot be concatenated
+    fn try_concat<'o>(&'s self, other: &'o Self) -> Result<Self, Error>;
 }
 
 /// Join by index
 pub trait Join: Sized {
-    fn join_inner(&self, other: &Self) -> Self;
+    /// Result of inner join
+    type Output;
+    /// Result of left, right and outer join, which can contain nulls
+    type NullableOutput;
+
+    fn join_inner(&self, other: &Self) -> Self::Output;
+    fn join_left(&self, other: &Self) -> Self::NullableOutput;
+    fn join_right(&self, other: &Self) -> Self::NullableOutput;
+    fn join_outer(&self, other: &Self) -> Self::NullableOutput;
 }
 
 pub trait Apply<'s, R> {
@@ -106,7 +162,7 @@ pub trait Apply<'s, R> {
     type FOut;
     type Out;
 
-    fn apply<'f>(&'s self, func: &'f Fn(&Self::In) -> Self::FOut) -> Self::Out;
+    fn apply<'f>(&'s self, func: &'f dyn Fn(&Self::In) -> Self::FOut) -> Self::Out;
 }
 
 pub trait BasicAggregation<'s> {
@@ -0,0 +1,235 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+#[test]
+fn test_block_creation_from_col_vec() {
+    let values = vec![1, 2, 3, 4, 5, 6];
+    let b = Block::<i64, i64, &str>::from_col_vec(values, vec![10, 20, 30], vec!["X", "Y"]);
+    assert_eq!(b.len(), 3);
+
+    let exp = Block::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]], vec![10, 20, 30], vec!["X", "Y"]);
+    assert_eq!(b, exp);
+}
+
+#[test]
+fn test_block_creation_from_row_vec() {
+    let values = vec![1, 2, 3, 4, 5, 6];
+    let b = Block::<i64, i64, &str>::from_row_vec(values, vec![10, 20, 30], vec!["X", "Y"]);
+    assert_eq!(b.len(), 3);
+
+    let exp = Block::from_vec(vec![vec![1, 3, 5], vec![2, 4, 6]], vec![10, 20, 30], vec!["X", "Y"]);
+    assert_eq!(b, exp);
+}
+
+#[test]
+#[should_panic]
+fn test_block_creation_length_mismatch() {
+    Block::<i64, i64, &str>::from_vec(vec![vec![1, 2, 3], vec![4, 5]], vec![10, 20, 30], vec!["X", "Y"]);
+}
+
+#[test]
+fn test_block_from_series() {
+    let s = Series::<f64, &str>::new(vec![1., 2., 3.], vec!["A", "B", "C"]);
+    let mut b = Block::from_series(s, "X");
+    b.insert("Y", vec![4., 5., 6.]);
+
+    let exp = Block::from_vec(vec![vec![1., 2., 3.], vec![4., 5., 6.]], vec!["A", "B", "C"], vec!["X", "Y"]);
+    assert_eq!(b, exp);
+}
+
+#[test]
+fn test_block_row_selection() {
+    let b = Block::<i64, &str, &str>::from_vec(
+        vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]],
+        vec!["A", "B", "C"],
+        vec!["X", "Y", "Z"],
+    );
+
+    assert_eq!(b.loc(&"B"), Series::new(vec![2, 5, 8], vec!["X", "Y", "Z"]));
+    assert_eq!(b.iloc(&2), Series::new(vec![3, 6, 9], vec!["X", "Y", "Z"]));
+
+    let exp = Block::from_vec(vec![vec![3, 1], vec![6, 4], vec![9, 7]], vec!["C", "A"], vec!["X", "Y", "Z"]);
+    assert_eq!(b.locs(&["C", "A"]), exp);
+    assert_eq!(b.ilocs(&[2, 0]), exp);
+
+    let exp = Block::from_vec(vec![vec![1, 3], vec![4, 6], vec![7, 9]], vec!["A", "C"], vec!["X", "Y", "Z"]);
+    assert_eq!(b.blocs(&[true, false, true]), exp);
+
+    let exp = Block::from_vec(vec![vec![1, 2], vec![4, 5], vec![7, 8]], vec!["A", "B"], vec!["X", "Y", "Z"]);
+    assert_eq!(b.head(2), exp);
+}
+
+#[test]
+fn test_block_column_selection() {
+    let b = Block::<i64, &str, &str>::from_vec(
+        vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]],
+        vec!["A", "B", "C"],
+        vec!["X", "Y", "Z"],
+    );
+
+    assert_eq!(b.get(&"Y"), Series::new(vec![4, 5, 6], vec!["A", "B", "C"]));
+    assert_eq!(b.iget(&2), Series::new(vec![7, 8, 9], vec!["A", "B", "C"]));
+
+    let exp = Block::from_vec(vec![vec![7, 8, 9], vec![1, 2, 3]], vec!["A", "B", "C"], vec!["Z", "X"]);
+    assert_eq!(b.gets(&["Z", "X"]), exp);
+    assert_eq!(b.igets(&[2, 0]), exp);
+}
+
+#[test]
+fn test_block_ops() {
+    let b = Block::<i64, &str, &str>::from_vec(vec![vec![1, 2], vec![3, 4]], vec!["A", "B"], vec!["X", "Y"]);
+
+    let exp = Block::from_vec(vec![vec![3, 4], vec![5, 6]], vec!["A", "B"], vec!["X", "Y"]);
+    assert_eq!(&b + 2, exp);
+
+    let exp = Block::from_vec(vec![vec![2, 4], vec![6, 8]], vec!["A", "B"], vec!["X", "Y"]);
+    assert_eq!(&b + &b, exp);
+    assert_eq!(b.clone() * 2, exp);
+}
+
+#[test]
+fn test_block_aggregation() {
+    let b = Block::<i64, &str, &str>::from_vec(
+        vec![vec![1, 2, 3], vec![4, 5, 6]],
+        vec!["A", "B", "C"],
+        vec!["X", "Y"],
+    );
+    assert_eq!(b.sum(), Series::new(vec![6, 15], vec!["X", "Y"]));
+    assert_eq!(b.count(), Series::new(vec![3, 3], vec!["X", "Y"]));
+    assert_eq!(b.mean(), Series::new(vec![2., 5.], vec!["X", "Y"]));
+    assert_eq!(b.min(), Series::new(vec![1, 4], vec!["X", "Y"]));
+    assert_eq!(b.max(), Series::new(vec![3, 6], vec!["X", "Y"]));
+
+    let res = b.describe();
+    assert_eq!(res.index.clone().into_owned(), Indexer::new(vec!["count", "mean", "std", "min", "max"]));
+    assert_eq!(res.get(&"X").values.into_owned(), vec![3., 2., 0.816496580927726, 1., 3.]);
+}
+
+#[test]
+fn test_block_groupby() {
+    let b = Block::<i64, &str, &str>::from_vec(
+        vec![vec![1, 2, 3, 4], vec![5, 6, 7, 8]],
+        vec!["A", "B", "C", "D"],
+        vec!["X", "Y"],
+    );
+    let bg = b.groupby(&[1, 2, 1, 2]);
+    assert_eq!(bg.groups(), vec![1, 2]);
+
+    let exp = Block::from_vec(vec![vec![1, 3], vec![5, 7]], vec!["A", "C"], vec!["X", "Y"]);
+    assert_eq!(bg.get_group(&1), exp);
+
+    let exp = Block::from_vec(vec![vec![4, 6], vec![12, 14]], vec![1, 2], vec!["X", "Y"]);
+    assert_eq!(bg.sum(), exp);
+
+    let exp = Block::from_vec(vec![vec![2., 3.], vec![6., 7.]], vec![1, 2], vec!["X", "Y"]);
+    assert_eq!(bg.mean(), exp);
+
+    let exp = Block::from_vec(vec![vec![3, 4], vec![7, 8]], vec![1, 2], vec!["X", "Y"]);
+    assert_eq!(bg.max(), exp);
+}
+
+#[test]
+fn test_block_reshape() {
+    let b1 = Block::<i64, &str, &str>::from_vec(vec![vec![1, 2], vec![3, 4]], vec!["A", "B"], vec!["X", "Y"]);
+    let b2 = Block::<i64, &str, &str>::from_vec(vec![vec![5], vec![6]], vec!["C"], vec!["X", "Y"]);
+
+    let exp = Block::from_vec(vec![vec![1, 2, 5], vec![3, 4, 6]], vec!["A", "B", "C"], vec!["X", "Y"]);
+    assert_eq!(b1.append(&b2), exp);
+
+    let b3 = Block::<i64, &str, &str>::from_vec(vec![vec![7, 8]], vec!["A", "B"], vec!["Z"]);
+    let exp = Block::from_vec(vec![vec![1, 2], vec![3, 4], vec![7, 8]], vec!["A", "B"], vec!["X", "Y", "Z"]);
+    assert_eq!(b1.concat(&b3), exp);
+
+    let b4 = Block::<i64, &str, &str>::from_vec(vec![vec![10, 20]], vec!["B", "C"], vec!["Z"]);
+    let exp = Block::from_vec(vec![vec![2], vec![4], vec![10]], vec!["B"], vec!["X", "Y", "Z"]);
+    assert_eq!(b1.join_inner(&b4), exp);
+}
+
+#[test]
+fn test_block_join_nullable() {
+    let b1 = Block::<i64, &str, &str>::from_vec(vec![vec![1, 2], vec![3, 4]], vec!["A", "B"], vec!["X", "Y"]);
+    let b2 = Block::<i64, &str, &str>::from_vec(vec![vec![10, 20]], vec!["B", "C"], vec!["Z"]);
+
+    let exp = DataFrame::from_vec(
+        vec![
+            array![1i64, 2],
+            array![3i64, 4],
+            Array::Int64Array(NullVec::with_mask(vec![0, 10], Some(vec![true, false]))),
+        ],
+        vec!["A", "B"],
+        vec!["X", "Y", "Z"],
+    );
+    assert_eq!(b1.join_left(&b2), exp);
+
+    let exp = DataFrame::from_vec(
+        vec![
+            Array::Int64Array(NullVec::with_mask(vec![2, 0], Some(vec![false, true]))),
+            Array::Int64Array(NullVec::with_mask(vec![4, 0], Some(vec![false, true]))),
+            array![10i64, 20],
+        ],
+        vec!["B", "C"],
+        vec!["X", "Y", "Z"],
+    );
+    assert_eq!(b1.join_right(&b2), exp);
+
+    let exp = DataFrame::from_vec(
+        vec![
+            Array::Int64Array(NullVec::with_mask(vec![1, 2, 0], Some(vec![false, false, true]))),
+            Array::Int64Array(NullVec::with_mask(vec![3, 4, 0], Some(vec![false, false, true]))),
+            Array::Int64Array(NullVec::with_mask(vec![0, 10, 20], Some(vec![true, false, false]))),
+        ],
+        vec!["A", "B", "C"],
+        vec!["X", "Y", "Z"],
+    );
+    assert_eq!(b1.join_outer(&b2), exp);
+}
+
+#[test]
+fn test_block_transpose() {
+    let b = Block::<i64, &str, &str>::from_vec(
+        vec![vec![1, 2, 3], vec![4, 5, 6]],
+        vec!["A", "B", "C"],
+        vec!["X", "Y"],
+    );
+    let exp = Block::from_vec(vec![vec![1, 4], vec![2, 5], vec![3, 6]], vec!["X", "Y"], vec!["A", "B", "C"]);
+    assert_eq!(b.transpose(), exp);
+}
+
+#[test]
+fn test_block_to_frame() {
+    let b = Block::<f64, &str, &str>::from_vec(
+        vec![vec![1., 2., 3.], vec![4., 5., 6.]],
+        vec!["A", "B", "C"],
+        vec!["X", "Y"],
+    );
+    let df: DataFrame<&str, &str> = b.into();
+    let exp = DataFrame::from_vec(
+        vec![array![1., 2., 3.], array![4., 5., 6.]],
+        vec!["A", "B", "C"],
+        vec!["X", "Y"],
+    );
+    assert_eq!(df, exp);
+}
+
+#[test]
+fn test_frame_to_block() {
+    let df = DataFrame::from_vec(
+        vec![array![1i64, 2, 3], array![4i64, 5, 6]],
+        vec!["A", "B", "C"],
+        vec!["X", "Y"],
+    );
+    let b = df.as_block::<i64>().unwrap();
+    let exp = Block::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]], vec!["A", "B", "C"], vec!["X", "Y"]);
+    assert_eq!(b, exp);
+
+    // dtype mismatch
+    assert!(df.as_block::<f64>().is_err());
+
+    let df = DataFrame::from_vec(
+        vec![array![1i64, 2, 3], array![4., 5., 6.]],
+        vec!["A", "B", "C"],
+        vec!["X", "Y"],
+    );
+    assert!(df.as_block::<i64>().is_err());
+}
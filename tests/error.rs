@@ -0,0 +1,146 @@
+use std::borrow::Cow;
+
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+#[test]
+fn test_error_display() {
+    assert_eq!(Error::LengthMismatch(3, 2).to_string(),
+               "Length mismatch! (expected: 3, actual: 2)");
+    assert_eq!(Error::IndexMismatch.to_string(), "index must be identical");
+    assert_eq!(Error::GroupNotFound.to_string(), "Group not found!");
+}
+
+#[test]
+fn test_indexer_try_get_loc() {
+    let idx = Indexer::<i64>::new(vec![1, 2, 2]);
+    assert_eq!(idx.try_get_loc(&1), Ok(0));
+    assert_eq!(idx.try_get_loc(&2), Err(Error::DuplicatedLabel));
+    assert_eq!(idx.try_get_loc(&3), Err(Error::LabelNotFound));
+}
+
+#[test]
+fn test_indexer_try_get_all_locs() {
+    let idx = Indexer::<i64>::new(vec![1, 2, 2]);
+    assert_eq!(idx.try_get_all_locs(&1), Ok(vec![0]));
+    assert_eq!(idx.try_get_all_locs(&2), Ok(vec![1, 2]));
+    assert_eq!(idx.try_get_all_locs(&3), Err(Error::LabelNotFound));
+}
+
+#[test]
+fn test_series_try_new() {
+    let s = Series::<i64, i64>::try_new(vec![1, 2, 3], vec![10, 20, 30]).unwrap();
+    assert_eq!(s, Series::<i64, i64>::new(vec![1, 2, 3], vec![10, 20, 30]));
+
+    let res = Series::<i64, i64>::try_new(vec![1, 2, 3], vec![10, 20]);
+    assert_eq!(res.err(), Some(Error::LengthMismatch(2, 3)));
+}
+
+#[test]
+fn test_series_try_loc() {
+    let s = Series::<i64, i64>::new(vec![1, 2, 3], vec![10, 20, 30]);
+    assert_eq!(s.try_loc(&20), Ok(2));
+    assert_eq!(s.try_loc(&40), Err(Error::LabelNotFound));
+}
+
+#[test]
+fn test_series_try_append() {
+    let s1 = Series::<i64, i64>::new(vec![1, 2], vec![10, 20]);
+    let s2 = Series::<i64, i64>::new(vec![3], vec![30]);
+    let exp = Series::<i64, i64>::new(vec![1, 2, 3], vec![10, 20, 30]);
+    assert_eq!(s1.try_append(&s2).unwrap(), exp);
+
+    // values and index of other have different lengths
+    let mut s3 = Series::<i64, i64>::new(vec![3], vec![30]);
+    s3.values = Cow::Owned(vec![3, 4]);
+    assert_eq!(s1.try_append(&s3).err(), Some(Error::LengthMismatch(1, 2)));
+
+    let mut s4 = Series::<i64, i64>::new(vec![3], vec![30]);
+    s4.mask = Some(vec![false, true]);
+    assert_eq!(s1.try_append(&s4).err(), Some(Error::LengthMismatch(1, 2)));
+}
+
+#[test]
+fn test_frame_try_from_vec() {
+    let res = DataFrame::<i64, &str>::try_from_vec(vec![array![1i64, 2], array![3., 4.]],
+                                                    vec![10, 20],
+                                                    vec!["X", "Y"]);
+    assert!(res.is_ok());
+
+    let res = DataFrame::<i64, &str>::try_from_vec(vec![array![1i64, 2]],
+                                                    vec![10, 20],
+                                                    vec!["X", "Y"]);
+    assert_eq!(res.err(), Some(Error::LengthMismatch(2, 1)));
+
+    let res = DataFrame::<i64, &str>::try_from_vec(vec![array![1i64, 2], array![3., 4., 5.]],
+                                                    vec![10, 20],
+                                                    vec!["X", "Y"]);
+    assert_eq!(res.err(), Some(Error::LengthMismatch(2, 3)));
+}
+
+#[test]
+fn test_frame_try_insert() {
+    let mut df = DataFrame::from_vec(vec![array![1i64, 2]], vec![10, 20], vec!["X"]);
+    assert_eq!(df.try_insert(array![3i64], "Y"), Err(Error::LengthMismatch(2, 1)));
+    assert_eq!(df.columns.len(), 1);
+
+    assert_eq!(df.try_insert(array![3i64, 4], "Y"), Ok(()));
+    let exp = DataFrame::from_vec(vec![array![1i64, 2], array![3i64, 4]],
+                                  vec![10, 20],
+                                  vec!["X", "Y"]);
+    assert_eq!(df, exp);
+}
+
+#[test]
+fn test_frame_try_loc() {
+    let df = DataFrame::from_vec(vec![array![1i64, 2]], vec![10, 20], vec!["X"]);
+    assert!(df.try_loc(&10).is_ok());
+    assert_eq!(df.try_loc(&30).err(), Some(Error::LabelNotFound));
+}
+
+#[test]
+fn test_frame_try_append() {
+    let df1 = DataFrame::from_vec(vec![array![1i64, 2]], vec![10, 20], vec!["X"]);
+    let df2 = DataFrame::from_vec(vec![array![3i64]], vec![30], vec!["X"]);
+    let exp = DataFrame::from_vec(vec![array![1i64, 2, 3]], vec![10, 20, 30], vec!["X"]);
+    assert_eq!(df1.try_append(&df2).unwrap(), exp);
+
+    let df3 = DataFrame::from_vec(vec![array![3i64]], vec![30], vec!["Y"]);
+    assert_eq!(df1.try_append(&df3).err(), Some(Error::ColumnsMismatch));
+
+    let df4 = DataFrame::from_vec(vec![array![3.]], vec![30], vec!["X"]);
+    let exp = Error::DtypeMismatch("i64".to_string(), "f64".to_string());
+    assert_eq!(df1.try_append(&df4).err(), Some(exp));
+}
+
+#[test]
+fn test_frame_try_concat() {
+    let df1 = DataFrame::from_vec(vec![array![1i64, 2]], vec![10, 20], vec!["X"]);
+    let df2 = DataFrame::from_vec(vec![array![3i64, 4]], vec![10, 20], vec!["Y"]);
+    let exp = DataFrame::from_vec(vec![array![1i64, 2], array![3i64, 4]],
+                                  vec![10, 20],
+                                  vec!["X", "Y"]);
+    assert_eq!(df1.try_concat(&df2).unwrap(), exp);
+
+    let df3 = DataFrame::from_vec(vec![array![3i64, 4]], vec![10, 30], vec!["Y"]);
+    assert_eq!(df1.try_concat(&df3).err(), Some(Error::IndexMismatch));
+}
+
+#[test]
+fn test_block_try_append_concat() {
+    let b1 = Block::<i64, i64, &str>::from_vec(vec![vec![1, 2]], vec![10, 20], vec!["X"]);
+    let b2 = Block::<i64, i64, &str>::from_vec(vec![vec![3]], vec![30], vec!["Y"]);
+    assert_eq!(b1.try_append(&b2).err(), Some(Error::ColumnsMismatch));
+    assert_eq!(b1.try_concat(&b2).err(), Some(Error::IndexMismatch));
+}
+
+#[test]
+fn test_groupby_try_get_group() {
+    let s = Series::<i64, i64>::new(vec![1, 2, 3], vec![10, 20, 30]);
+    let gp = s.groupby(&[1, 2, 1]);
+
+    let exp = Series::<i64, i64>::new(vec![1, 3], vec![10, 30]);
+    assert_eq!(gp.try_get_group(&1).unwrap(), exp);
+    assert_eq!(gp.try_get_group(&3).err(), Some(Error::GroupNotFound));
+}
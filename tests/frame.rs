@@ -199,3 +199,308 @@ fn test_frame_properties() {
     let exp: Vec<bool> = vec![false, true, false, true];
     assert_eq!(df.is_numeric(), exp);
 }
+
+#[test]
+fn test_frame_loc() {
+    let values = vec![
+        array![1i64, 2, 3],
+        array![4.5f64, 5.5, 6.5],
+        array!["a".to_string(), "b".to_string(), "c".to_string()],
+    ];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C"], vec!["X", "Y", "Z"]);
+
+    let res = df.loc(&"B");
+    let exp = Row::new(
+        vec![Scalar::i64(2), Scalar::f64(5.5), Scalar::String("b".to_string())],
+        vec!["X", "Y", "Z"],
+    );
+    assert_eq!(res, exp);
+    assert_eq!(res.get(&"Y"), &Scalar::f64(5.5));
+
+    let res = df.iloc(&2);
+    let exp = Row::new(
+        vec![Scalar::i64(3), Scalar::f64(6.5), Scalar::String("c".to_string())],
+        vec!["X", "Y", "Z"],
+    );
+    assert_eq!(res, exp);
+}
+
+#[test]
+#[should_panic]
+fn test_frame_iloc_panic() {
+    let values = vec![array![1i64, 2, 3], array![4.5f64, 5.5, 6.5]];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C"], vec!["X", "Y"]);
+    df.iloc(&3);
+}
+
+#[test]
+fn test_frame_loc_null() {
+    let values = vec![
+        Array::Int64Array(NullVec::with_mask(vec![1, 2, 3], Some(vec![false, true, false]))),
+        array![4.5f64, 5.5, 6.5],
+    ];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C"], vec!["X", "Y"]);
+    let res = df.loc(&"B");
+    let exp = Row::new(vec![Scalar::Null, Scalar::f64(5.5)], vec!["X", "Y"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_frame_blocs() {
+    let values = vec![
+        array![1i64, 2, 3, 4],
+        array![4.5f64, 5.5, 6.5, 7.5],
+        array![true, false, true, false],
+    ];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C", "D"], vec!["X", "Y", "Z"]);
+
+    let res = df.blocs(&[true, false, false, true]);
+    let exp_values = vec![array![1i64, 4], array![4.5f64, 7.5], array![true, false]];
+    let exp = DataFrame::from_vec(exp_values, vec!["A", "D"], vec!["X", "Y", "Z"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+#[should_panic]
+fn test_frame_blocs_length_mismatch() {
+    let values = vec![array![1i64, 2, 3], array![4.5f64, 5.5, 6.5]];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C"], vec!["X", "Y"]);
+    df.blocs(&[true, false]);
+}
+
+#[test]
+fn test_frame_from_rows() {
+    let values = vec![
+        array![1i64, 2, 3],
+        array![4.5f64, 5.5, 6.5],
+        array!["a".to_string(), "b".to_string(), "c".to_string()],
+    ];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C"], vec!["X", "Y", "Z"]);
+
+    let rows = vec![df.iloc(&2), df.iloc(&0)];
+    let res = DataFrame::from_rows(rows, vec!["C", "A"]);
+    assert_eq!(res, df.locs(&["C", "A"]));
+}
+
+#[test]
+fn test_frame_from_rows_null() {
+    let values = vec![
+        Array::Int64Array(NullVec::with_mask(vec![0, 2], Some(vec![true, false]))),
+        Array::Float64Array(NullVec::with_mask(vec![0., 0.], Some(vec![true, true]))),
+    ];
+    let df = DataFrame::from_vec(values, vec!["A", "B"], vec!["X", "Y"]);
+
+    // dtype is inferred from the first non-null value
+    let rows = vec![df.iloc(&0), df.iloc(&1)];
+    let res = DataFrame::from_rows(rows, vec!["A", "B"]);
+    assert_eq!(res, df);
+    assert_eq!(res.dtypes(), vec!["i64", "f64"]);
+}
+
+#[test]
+fn test_frame_get() {
+    let values = vec![
+        array![1i64, 2, 3],
+        array![4.5f64, 5.5, 6.5],
+        array!["a".to_string(), "b".to_string(), "c".to_string()],
+    ];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C"], vec!["X", "Y", "Z"]);
+
+    let res = df.get(&"Y");
+    let exp = Column::new(array![4.5f64, 5.5, 6.5], vec!["A", "B", "C"]);
+    assert_eq!(res, exp);
+    assert_eq!(res.dtype(), "f64".to_string());
+    assert_eq!(res.loc(&"B"), Scalar::f64(5.5));
+
+    let res = df.iget(&2);
+    let exp = Column::new(
+        array!["a".to_string(), "b".to_string(), "c".to_string()],
+        vec!["A", "B", "C"],
+    );
+    assert_eq!(res, exp);
+    assert_eq!(res.ilocs(&[2, 0]), Column::new(array!["c".to_string(), "a".to_string()],
+                                              vec!["C", "A"]));
+}
+
+#[test]
+#[should_panic]
+fn test_frame_get_panic() {
+    let values = vec![array![1i64, 2, 3], array![4.5f64, 5.5, 6.5]];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C"], vec!["X", "Y"]);
+    df.get(&"Z");
+}
+
+#[test]
+fn test_frame_get_as() {
+    let values = vec![
+        array![1i64, 2, 3],
+        array![4.5f64, 5.5, 6.5],
+        array!["a".to_string(), "b".to_string(), "c".to_string()],
+    ];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C"], vec!["X", "Y", "Z"]);
+
+    let res: Series<f64, &str> = df.get_as(&"Y").unwrap();
+    assert_eq!(res, Series::new(vec![4.5, 5.5, 6.5], vec!["A", "B", "C"]));
+
+    let res = df.get_as::<i64>(&"X").unwrap();
+    assert_eq!(res, Series::new(vec![1, 2, 3], vec!["A", "B", "C"]));
+
+    let res = df.iget_as::<String>(&2).unwrap();
+    let exp = Series::new(
+        vec!["a".to_string(), "b".to_string(), "c".to_string()],
+        vec!["A", "B", "C"],
+    );
+    assert_eq!(res, exp);
+
+    // dtype mismatch
+    let exp = Error::DtypeMismatch("f64".to_string(), "i64".to_string());
+    assert_eq!(df.get_as::<f64>(&"X").err(), Some(exp));
+    assert!(df.get_as::<i32>(&"X").is_err());
+    assert!(df.get_as::<i64>(&"Z").is_err());
+    assert_eq!(df.get_as::<i64>(&"W").err(), Some(Error::LabelNotFound));
+
+    let res = df.get(&"X").as_series::<i64>().unwrap();
+    assert_eq!(res, Series::new(vec![1, 2, 3], vec!["A", "B", "C"]));
+}
+
+#[test]
+fn test_frame_get_as_null() {
+    let values = vec![
+        Array::Int64Array(NullVec::with_mask(vec![1, 2, 3], Some(vec![false, true, false]))),
+        Array::Float64Array(NullVec::with_mask(vec![1., 2., 3.], Some(vec![false, true, false]))),
+    ];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C"], vec!["X", "Y"]);
+
+    // null is kept as mask
+    let res = df.get_as::<i64>(&"X").unwrap();
+    assert_eq!(res, Series::with_mask(vec![1, 0, 3], vec!["A", "B", "C"], vec![false, true, false]));
+
+    let res = df.get_as::<f64>(&"Y").unwrap();
+    assert_eq!(res.values[0], 1.);
+    assert!(res.values[1].is_nan());
+    assert_eq!(res.values[2], 3.);
+    assert_eq!(res.mask, Some(vec![false, true, false]));
+}
+
+#[test]
+fn test_locs_duplicates() {
+    let values = vec![array![1, 2, 3, 4], array![1.1, 2.1, 3.1, 4.1]];
+    let df = DataFrame::from_vec(values, vec![10, 20, 10, 30], vec!["X", "Y"]);
+
+    let res = df.locs(&[10, 30]);
+    let exp = DataFrame::from_vec(
+        vec![array![1, 3, 4], array![1.1, 3.1, 4.1]],
+        vec![10, 10, 30],
+        vec!["X", "Y"],
+    );
+    assert_eq!(res, exp);
+
+    let row = df.loc(&20);
+    assert_eq!(row.get(&"X"), &Scalar::i32(2));
+
+    let res = df.loc_all(&10);
+    let exp = DataFrame::from_vec(
+        vec![array![1, 3], array![1.1, 3.1]],
+        vec![10, 10],
+        vec!["X", "Y"],
+    );
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_drop_duplicates() {
+    let values = vec![
+        array!["a", "b", "a", "a"],
+        array![1, 2, 1, 1],
+        array![1.1, 2.1, 1.1, 3.1],
+    ];
+    let df = DataFrame::from_vec(values, vec![10, 20, 30, 40], vec!["X", "Y", "Z"]);
+
+    let exp = Series::new(vec![false, false, true, false], vec![10, 20, 30, 40]);
+    assert_eq!(df.duplicated(None, Duplicates::First), exp);
+
+    let res = df.drop_duplicates(None, Duplicates::First);
+    let exp = DataFrame::from_vec(
+        vec![array!["a", "b", "a"], array![1, 2, 1], array![1.1, 2.1, 3.1]],
+        vec![10, 20, 40],
+        vec!["X", "Y", "Z"],
+    );
+    assert_eq!(res, exp);
+
+    let res = df.drop_duplicates(Some(&["X", "Y"]), Duplicates::Last);
+    let exp = DataFrame::from_vec(
+        vec![array!["b", "a"], array![2, 1], array![2.1, 3.1]],
+        vec![20, 40],
+        vec!["X", "Y", "Z"],
+    );
+    assert_eq!(res, exp);
+
+    let res = df.drop_duplicates(Some(&["X"]), Duplicates::None);
+    let exp = DataFrame::from_vec(vec![array!["b"], array![2], array![2.1]], vec![20], vec!["X", "Y", "Z"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_column_factorize() {
+    let values = vec![
+        array!["x", "y", "x", "z"],
+        Array::Int64Array(NullVec::with_mask(vec![3, 0, 1, 3], Some(vec![false, true, false, false]))),
+    ];
+    let df = DataFrame::from_vec(values, vec![10, 20, 30, 40], vec!["X", "Y"]);
+
+    let (codes, uniques) = df.get(&"X").factorize(false);
+    assert_eq!(codes, vec![0, 1, 0, 2]);
+    assert_eq!(uniques, array!["x", "y", "z"]);
+
+    let (codes, uniques) = df.get(&"Y").factorize(true);
+    assert_eq!(codes, vec![1, USIZE_MISSING, 0, 1]);
+    assert_eq!(uniques, array![1i64, 3]);
+
+    // Null never collides with a valid code
+    let (codes, uniques) = df.get(&"Y").factorize(false);
+    assert_eq!(codes, vec![0, USIZE_MISSING, 1, 0]);
+    assert_eq!(uniques, array![3i64, 1]);
+}
+
+#[test]
+fn test_frame_filter() {
+    let values = vec![
+        array![1i64, 2, 3, 4],
+        Array::Float64Array(NullVec::with_mask(vec![1.5, 0., 3.5, 4.5],
+                                               Some(vec![false, true, false, false]))),
+    ];
+    let df = DataFrame::from_vec(values, vec!["A", "B", "C", "D"], vec!["X", "Y"]);
+
+    let x = df.get_as::<i64>(&"X").unwrap();
+    let res = df.filter(&(x.ge(&2) & x.elem_ne(&4)));
+    let exp_values = vec![
+        array![2i64, 3],
+        Array::Float64Array(NullVec::with_mask(vec![0., 3.5], Some(vec![true, false]))),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec!["B", "C"], vec!["X", "Y"]);
+    assert_eq!(res, exp);
+
+    // null is regarded as false
+    let y = df.get_as::<f64>(&"Y").unwrap();
+    let res = df.filter(&!y.gt(&4.));
+    let exp_values = vec![
+        array![1i64, 2, 3],
+        Array::Float64Array(NullVec::with_mask(vec![1.5, 0., 3.5], Some(vec![false, true, false]))),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec!["A", "B", "C"], vec!["X", "Y"]);
+    assert_eq!(res, exp);
+
+    let res = df.filter(&y.lt(&4.));
+    let exp_values = vec![array![1i64, 3], array![1.5, 3.5]];
+    let exp = DataFrame::from_vec(exp_values, vec!["A", "C"], vec!["X", "Y"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+#[should_panic]
+fn test_frame_filter_index_mismatch() {
+    let df = DataFrame::from_vec(vec![array![1i64, 2]], vec!["A", "B"], vec!["X"]);
+    let mask = Series::new(vec![true, false], vec!["A", "C"]);
+    df.filter(&mask);
+}
+
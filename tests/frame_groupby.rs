@@ -26,3 +26,247 @@ fn test_frame_get_group() {
     assert_eq!(df1.index, exp.index);
     assert_eq!(df1.columns, exp.columns);
 }
+
+#[test]
+fn test_frame_groupby_columns() {
+    let values = vec![
+        array!["east".to_string(), "west".to_string(), "east".to_string(), "east".to_string()],
+        array![1i64, 2, 1, 2],
+        array![1.5, 2.5, 3.5, 4.5],
+    ];
+    let df = DataFrame::from_vec(values, vec![10, 20, 30, 40], vec!["region", "product", "sales"]);
+
+    let dg = df.groupby_columns(&["region", "product"]);
+    let groups = dg.groups();
+    assert_eq!(groups.len(), 3);
+    let labels: Vec<String> = groups.iter().map(|x| x.to_string()).collect();
+    assert_eq!(labels, vec!["(east, 1)", "(east, 2)", "(west, 2)"]);
+
+    let key = HashableTuple::from(vec![Scalar::from("east"), Scalar::i64(1)]);
+    let df1 = dg.get_group(&key);
+    let exp_values = vec![
+        array!["east".to_string(), "east".to_string()],
+        array![1i64, 1],
+        array![1.5, 3.5],
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 30], vec!["region", "product", "sales"]);
+    assert_eq!(df1, exp);
+}
+
+fn grouped_frame<'a>() -> DataFrame<'a, 'a, 'a, i64, &'a str> {
+    let values = vec![
+        array!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string(), "e".to_string()],
+        array![1i64, 2, 3, 4, 5],
+        array![1.5, 2.5, 3.5, 4.5, 5.5],
+    ];
+    DataFrame::from_vec(values, vec![10, 20, 30, 40, 50], vec!["X", "Y", "Z"])
+}
+
+#[test]
+fn test_frame_groupby_sum_count() {
+    let df = grouped_frame();
+    let dg = df.groupby(&["A", "B", "A", "A", "B"]);
+
+    let exp = DataFrame::from_vec(vec![array![8i64, 7], array![9.5, 8.]],
+                                  vec!["A", "B"], vec!["Y", "Z"]);
+    assert_eq!(dg.sum(), exp);
+
+    let exp = DataFrame::from_vec(vec![array![3usize, 2], array![3usize, 2]],
+                                  vec!["A", "B"], vec!["Y", "Z"]);
+    assert_eq!(dg.count(), exp);
+}
+
+#[test]
+fn test_frame_groupby_numeric() {
+    let df = grouped_frame();
+    let dg = df.groupby(&[1, 2, 1, 1, 2]);
+
+    let exp = DataFrame::from_vec(vec![array![8. / 3., 3.5], array![19. / 6., 4.]],
+                                  vec![1, 2], vec!["Y", "Z"]);
+    assert_eq!(dg.mean(), exp);
+
+    let exp = DataFrame::from_vec(vec![array![14. / 9., 2.25], array![14. / 9., 2.25]],
+                                  vec![1, 2], vec!["Y", "Z"]);
+    let var = dg.var();
+    assert_eq!(var.index, exp.index);
+    assert_eq!(var.columns, exp.columns);
+    for (res, e) in var.values.iter().zip(exp.values.iter()) {
+        for i in 0..2 {
+            let diff = f64::from(res.iloc(&i).as_f64()) - f64::from(e.iloc(&i).as_f64());
+            assert!(diff.abs() < 1e-10);
+        }
+    }
+}
+
+#[test]
+fn test_frame_groupby_min_max() {
+    let df = grouped_frame();
+    let dg = df.groupby(&[1, 2, 1, 1, 2]);
+
+    let exp = DataFrame::from_vec(vec![array![1i64, 2], array![1.5, 2.5]],
+                                  vec![1, 2], vec!["Y", "Z"]);
+    assert_eq!(dg.min(), exp);
+
+    let exp = DataFrame::from_vec(vec![array![4i64, 5], array![4.5, 5.5]],
+                                  vec![1, 2], vec!["Y", "Z"]);
+    assert_eq!(dg.max(), exp);
+}
+
+#[test]
+fn test_frame_groupby_describe() {
+    let df = grouped_frame();
+    let dg = df.groupby(&[1, 2, 1, 1, 2]);
+    let res = dg.describe();
+
+    assert_eq!(res.index, Indexer::new(vec![1, 2]).into());
+    assert_eq!(res.columns.len(), 10);
+    assert_eq!(res.columns.values[0], ("Y", "count"));
+    assert_eq!(res.columns.values[9], ("Z", "max"));
+    assert_eq!(res.values[0].as_ref(), &array![3., 2.]);
+    assert_eq!(res.values[3].as_ref(), &array![1., 2.]);
+    assert_eq!(res.values[9].as_ref(), &array![4.5, 5.5]);
+}
+
+#[test]
+fn test_frame_groupby_agg() {
+    let values = vec![
+        array!["x".to_string(), "y".to_string(), "x".to_string(), "x".to_string(), "z".to_string()],
+        array![1i64, 2, 3, 4, 5],
+        array![1.5, 2.5, 3.5, 4.5, 5.5],
+    ];
+    let df = DataFrame::from_vec(values, vec![10, 20, 30, 40, 50], vec!["X", "Y", "Z"]);
+    let dg = df.groupby(&[1, 2, 1, 1, 2]);
+
+    let range = |x: &Array| {
+        Scalar::f64(f64::from(x.max().as_f64()) - f64::from(x.min().as_f64()))
+    };
+    let res = dg.agg(&[
+        ("X", vec![AggFunc::NUnique, AggFunc::First, AggFunc::Last]),
+        ("Y", vec![AggFunc::Sum, AggFunc::Count]),
+        ("Z", vec![AggFunc::Mean, AggFunc::Min, AggFunc::Apply("range", &range)]),
+    ]);
+
+    let exp_values = vec![
+        array![1usize, 2],
+        array!["x".to_string(), "y".to_string()],
+        array!["x".to_string(), "z".to_string()],
+        array![8i64, 7],
+        array![3usize, 2],
+        array![9.5 / 3., 4.],
+        array![1.5, 2.5],
+        array![3., 3.],
+    ];
+    let exp_columns = vec![
+        ("X", "nunique"),
+        ("X", "first"),
+        ("X", "last"),
+        ("Y", "sum"),
+        ("Y", "count"),
+        ("Z", "mean"),
+        ("Z", "min"),
+        ("Z", "range"),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![1, 2], exp_columns);
+    assert!(res == exp);
+}
+
+#[test]
+#[should_panic]
+fn test_frame_groupby_agg_non_numeric() {
+    let df = grouped_frame();
+    let dg = df.groupby(&[1, 2, 1, 1, 2]);
+    dg.agg(&[("X", vec![AggFunc::Sum])]);
+}
+
+#[test]
+fn test_frame_groupby_try_agg() {
+    let df = grouped_frame();
+    let dg = df.groupby(&[1, 2, 1, 1, 2]);
+
+    let res = dg.try_agg(&[("X", vec![AggFunc::Mean])]);
+    let exp = Error::InvalidOperation("mean requires numeric values (dtype: str)".to_string());
+    assert_eq!(res.err(), Some(exp));
+
+    let res = dg.try_agg(&[("W", vec![AggFunc::Min])]);
+    assert_eq!(res.err(), Some(Error::LabelNotFound));
+
+    // min and max are valid on strings
+    let res = dg.try_agg(&[("X", vec![AggFunc::Min, AggFunc::Max])]).unwrap();
+    let exp_values = vec![array!["a".to_string(), "b".to_string()],
+                          array!["d".to_string(), "e".to_string()]];
+    let exp = DataFrame::from_vec(exp_values, vec![1, 2], vec![("X", "min"), ("X", "max")]);
+    assert!(res == exp);
+}
+
+#[test]
+fn test_frame_groupby_transform() {
+    let df = grouped_frame();
+    let dg = df.groupby(&[1, 2, 1, 1, 2]);
+
+    let demean = |x: &Array| {
+        let mean = f64::from(x.mean());
+        let values: Vec<Scalar> = x.clone().into();
+        let demeaned: Vec<f64> = values.iter().map(|v| f64::from(v.as_f64()) - mean).collect();
+        Array::new(demeaned)
+    };
+    let res = dg.transform(&demean);
+
+    let exp_values = vec![
+        array![1. - 8. / 3., -1.5, 3. - 8. / 3., 4. - 8. / 3., 1.5],
+        array![1.5 - 9.5 / 3., -1.5, 3.5 - 9.5 / 3., 4.5 - 9.5 / 3., 1.5],
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30, 40, 50], vec!["Y", "Z"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_frame_groupby_filter() {
+    let df = grouped_frame();
+    let dg = df.groupby(&[1, 2, 1, 1, 2]);
+
+    let res = dg.filter(&|x: &DataFrame<i64, &str>| x.len() > 2);
+    let exp_values = vec![
+        array!["a".to_string(), "c".to_string(), "d".to_string()],
+        array![1i64, 3, 4],
+        array![1.5, 3.5, 4.5],
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 30, 40], vec!["X", "Y", "Z"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_frame_groupby_sort_strategy() {
+    let df = grouped_frame();
+    let keys = vec![1, 2, 1, 1, 2];
+    let hg = GroupBy::with_strategy(&df, &keys, GroupStrategy::Hash);
+    let sg = GroupBy::with_strategy(&df, &keys, GroupStrategy::Sort);
+
+    assert_eq!(sg.groups(), vec![1, 2]);
+    assert_eq!(sg.get_group(&2), hg.get_group(&2));
+    assert_eq!(sg.mean(), hg.mean());
+    assert_eq!(sg.transform(&|x: &Array| x.clone()), hg.transform(&|x: &Array| x.clone()));
+}
+
+#[test]
+fn test_frame_groupby_columns_with() {
+    let values = vec![
+        Array::StringArray(NullVec::with_mask(
+            vec!["east".to_string(), "".to_string(), "west".to_string(), "east".to_string()],
+            Some(vec![false, true, false, false]),
+        )),
+        array![1.5, 2.5, 3.5, 4.5],
+    ];
+    let df = DataFrame::from_vec(values, vec![10, 20, 30, 40], vec!["region", "sales"]);
+
+    let options = GroupByOptions { sort: false, dropna: true, ..GroupByOptions::default() };
+    let dg = df.groupby_columns_with(&["region"], options);
+    let labels: Vec<String> = dg.groups().iter().map(|x| x.to_string()).collect();
+    assert_eq!(labels, vec!["(east)", "(west)"]);
+    let exp = DataFrame::from_vec(vec![array![6., 3.5]], dg.groups(), vec!["sales"]);
+    assert!(dg.sum() == exp);
+
+    let options = GroupByOptions { sort: false, dropna: false, ..GroupByOptions::default() };
+    let dg = df.groupby_columns_with(&["region"], options);
+    let labels: Vec<String> = dg.groups().iter().map(|x| x.to_string()).collect();
+    assert_eq!(labels, vec!["(east)", "(Null)", "(west)"]);
+}
@@ -0,0 +1,197 @@
+use std::borrow::Cow;
+
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+fn columns(names: &[&str]) -> Vec<String> {
+    names.iter().map(|x| x.to_string()).collect()
+}
+
+#[test]
+fn test_dataframe_merge_inner() {
+    let values1 = vec![array!["a", "b", "a", "c"], array![1i64, 2, 3, 4]];
+    let df1 = DataFrame::from_vec(values1, vec![10, 20, 30, 40], vec!["K", "X"]);
+
+    let values2 = vec![array!["a", "b", "d"], array![1.5, 2.5, 3.5]];
+    let df2 = DataFrame::from_vec(values2, vec![10, 20, 30], vec!["K", "Y"]);
+
+    let res = df1.merge(&df2, &["K"], JoinHow::Inner);
+    let exp_values = vec![
+        array!["a", "b", "a"],
+        array![1i64, 2, 3],
+        array![1.5, 2.5, 1.5],
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![0, 1, 2], columns(&["K", "X", "Y"]));
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_dataframe_merge_many_to_many() {
+    let values1 = vec![array![1i64, 2, 1], array!["x1", "x2", "x3"]];
+    let df1 = DataFrame::from_vec(values1, vec![0, 1, 2], vec!["K", "X"]);
+
+    let values2 = vec![array![1i64, 1, 3], array!["y1", "y2", "y3"]];
+    let df2 = DataFrame::from_vec(values2, vec![0, 1, 2], vec!["K", "Y"]);
+
+    let res = df1.merge(&df2, &["K"], JoinHow::Inner);
+    let exp_values = vec![
+        array![1i64, 1, 1, 1],
+        array!["x1", "x1", "x3", "x3"],
+        array!["y1", "y2", "y1", "y2"],
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![0, 1, 2, 3], columns(&["K", "X", "Y"]));
+    assert_eq!(res, exp);
+
+    let res = df1.merge(&df2, &["K"], JoinHow::Outer);
+    let exp_values = vec![
+        array![1i64, 1, 2, 1, 1, 3],
+        Array::StringArray(NullVec::with_mask(
+            ["x1", "x1", "x2", "x3", "x3", ""]
+                .iter()
+                .map(|x| x.to_string())
+                .collect(),
+            Some(vec![false, false, false, false, false, true]),
+        )),
+        Array::StringArray(NullVec::with_mask(
+            ["y1", "y2", "", "y1", "y2", "y3"]
+                .iter()
+                .map(|x| x.to_string())
+                .collect(),
+            Some(vec![false, false, true, false, false, false]),
+        )),
+    ];
+    let exp = DataFrame::from_vec(
+        exp_values,
+        vec![0, 1, 2, 3, 4, 5],
+        columns(&["K", "X", "Y"]),
+    );
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_dataframe_merge_left_right() {
+    let values1 = vec![array![1i64, 2, 3], array![1.1, 2.1, 3.1]];
+    let df1 = DataFrame::from_vec(values1, vec![0, 1, 2], vec!["K", "X"]);
+
+    let values2 = vec![array![3i64, 1, 4], array![true, false, true]];
+    let df2 = DataFrame::from_vec(values2, vec![0, 1, 2], vec!["K", "Y"]);
+
+    let res = df1.merge(&df2, &["K"], JoinHow::Left);
+    let exp_values = vec![
+        array![1i64, 2, 3],
+        array![1.1, 2.1, 3.1],
+        Array::BoolArray(NullVec::with_mask(
+            vec![false, false, true],
+            Some(vec![false, true, false]),
+        )),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![0, 1, 2], columns(&["K", "X", "Y"]));
+    assert_eq!(res, exp);
+
+    let res = df1.merge(&df2, &["K"], JoinHow::Right);
+    let exp_values = vec![
+        array![3i64, 1, 4],
+        array![3.1, 1.1, f64::NAN],
+        array![true, false, true],
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![0, 1, 2], columns(&["K", "X", "Y"]));
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_dataframe_merge_composite_keys() {
+    let values1 = vec![
+        array!["a", "a", "b", "b"],
+        array![1i64, 2, 1, 2],
+        array![10i64, 20, 30, 40],
+    ];
+    let df1 = DataFrame::from_vec(values1, vec![0, 1, 2, 3], vec!["K1", "K2", "X"]);
+
+    let values2 = vec![
+        array![2i64, 1, 2],
+        array!["b", "b", "a"],
+        array![1.5, 2.5, 3.5],
+    ];
+    let df2 = DataFrame::from_vec(values2, vec![0, 1, 2], vec!["K2", "K1", "Y"]);
+
+    let res = df1.merge(&df2, &["K1", "K2"], JoinHow::Inner);
+    let exp_values = vec![
+        array!["a", "b", "b"],
+        array![2i64, 1, 2],
+        array![20i64, 30, 40],
+        array![3.5, 2.5, 1.5],
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![0, 1, 2], columns(&["K1", "K2", "X", "Y"]));
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_dataframe_merge_suffixes() {
+    let values1 = vec![array![1i64, 2], array![1.1, 2.1]];
+    let df1 = DataFrame::from_vec(values1, vec![0, 1], vec!["K", "X"]);
+
+    let values2 = vec![array![2i64, 1], array![3.1, 4.1]];
+    let df2 = DataFrame::from_vec(values2, vec![0, 1], vec!["K", "X"]);
+
+    let res = df1.merge(&df2, &["K"], JoinHow::Inner);
+    assert_eq!(res.columns, Cow::Owned(Indexer::new(columns(&["K", "X_x", "X_y"]))));
+
+    let res = df1.merge_with(&df2, &["K"], JoinHow::Inner, ("_left", "_right"), None)
+        .unwrap();
+    let exp_values = vec![array![1i64, 2], array![1.1, 2.1], array![4.1, 3.1]];
+    let exp = DataFrame::from_vec(exp_values, vec![0, 1], columns(&["K", "X_left", "X_right"]));
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_dataframe_merge_validate() {
+    let values1 = vec![array![1i64, 2, 3], array![1.1, 2.1, 3.1]];
+    let df1 = DataFrame::from_vec(values1, vec![0, 1, 2], vec!["K", "X"]);
+
+    let values2 = vec![array![1i64, 1, 2], array![4.1, 5.1, 6.1]];
+    let df2 = DataFrame::from_vec(values2, vec![0, 1, 2], vec!["K", "Y"]);
+
+    let how = JoinHow::Inner;
+    let suffixes = ("_x", "_y");
+    assert!(df1.merge_with(&df2, &["K"], how, suffixes, Some(MergeValidate::OneToMany)).is_ok());
+    assert!(df1.merge_with(&df2, &["K"], how, suffixes, Some(MergeValidate::OneToOne)).is_err());
+    assert!(df1.merge_with(&df2, &["K"], how, suffixes, Some(MergeValidate::ManyToOne)).is_err());
+    let res = df1.merge_with(&df2, &["K"], how, suffixes, Some(MergeValidate::OneToOne));
+    let exp = Error::InvalidOperation("merge keys are not unique in right".to_string());
+    assert_eq!(res.err(), Some(exp));
+    assert!(df2.merge_with(&df1, &["K"], how, suffixes, Some(MergeValidate::ManyToOne)).is_ok());
+}
+
+#[test]
+#[should_panic]
+fn test_dataframe_merge_missing_key() {
+    let df1 = DataFrame::from_vec(vec![array![1i64, 2]], vec![0, 1], vec!["K"]);
+    let df2 = DataFrame::from_vec(vec![array![1i64, 2]], vec![0, 1], vec!["L"]);
+    df1.merge(&df2, &["K"], JoinHow::Inner);
+}
+
+#[test]
+fn test_dataframe_merge_with_invalid_keys() {
+    let df1 = DataFrame::from_vec(vec![array![1i64, 2]], vec![0, 1], vec!["K"]);
+    let df2 = DataFrame::from_vec(vec![array![1i64, 3]], vec![0, 1], vec!["L"]);
+    let suffixes = ("_x", "_y");
+
+    let res = df1.merge_with(&df2, &[], JoinHow::Inner, suffixes, None);
+    let exp = Error::InvalidOperation("at least one key column is required".to_string());
+    assert_eq!(res.err(), Some(exp));
+
+    let res = df1.merge_with(&df2, &["K"], JoinHow::Inner, suffixes, None);
+    assert_eq!(res.err(), Some(Error::LabelNotFound));
+
+    let res = df1.merge_with(&df1, &["K", "K"], JoinHow::Inner, suffixes, None);
+    assert_eq!(res.err(), Some(Error::DuplicatedLabel));
+
+    // dtype is checked regardless of how
+    let df3 = DataFrame::from_vec(vec![array![1i32, 3]], vec![0, 1], vec!["K"]);
+    for &how in &[JoinHow::Inner, JoinHow::Left, JoinHow::Right, JoinHow::Outer] {
+        let res = df1.merge_with(&df3, &["K"], how, suffixes, None);
+        let exp = Error::DtypeMismatch("i64".to_string(), "i32".to_string());
+        assert_eq!(res.err(), Some(exp));
+    }
+}
@@ -0,0 +1,142 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+fn frame<'a>() -> DataFrame<'a, 'a, 'a, i64, &'a str> {
+    let values = vec![
+        Array::Int64Array(NullVec::with_mask(vec![1, 0, 3, 0, 5],
+                                             Some(vec![false, true, false, true, false]))),
+        Array::StringArray(NullVec::with_mask(vec!["a".to_string(), "".to_string(), "c".to_string(),
+                                                   "".to_string(), "e".to_string()],
+                                              Some(vec![false, true, false, false, false]))),
+        Array::Float64Array(NullVec::with_mask(vec![0., 2., 0., 0., 5.],
+                                               Some(vec![true, false, true, true, false]))),
+    ];
+    DataFrame::from_vec(values, vec![10, 20, 30, 40, 50], vec!["X", "Y", "Z"])
+}
+
+#[test]
+fn test_frame_dropna_index() {
+    let df = frame();
+
+    let res = df.dropna(Axis::Index, DropHow::Any, None, None);
+    let exp_values = vec![array![5i64], array!["e".to_string()], array![5.]];
+    let exp = DataFrame::from_vec(exp_values, vec![50], vec!["X", "Y", "Z"]);
+    assert_eq!(res, exp);
+
+    // row 40 has non-null in Y only
+    let res = df.dropna(Axis::Index, DropHow::All, None, Some(&["X", "Z"]));
+    assert_eq!(res.index, Indexer::new(vec![10, 20, 30, 50]).into());
+
+    let res = df.dropna(Axis::Index, DropHow::Any, Some(2), None);
+    assert_eq!(res.index, Indexer::new(vec![10, 30, 50]).into());
+}
+
+#[test]
+fn test_frame_dropna_columns() {
+    let df = frame();
+
+    let res = df.dropna(Axis::Columns, DropHow::Any, Some(4), None);
+    assert_eq!(res.columns, Indexer::new(vec!["Y"]).into());
+
+    let res = df.dropna(Axis::Columns, DropHow::All, None, None);
+    assert_eq!(res, df);
+}
+
+#[test]
+#[should_panic]
+fn test_frame_dropna_columns_subset_panic() {
+    let df = frame();
+    df.dropna(Axis::Columns, DropHow::Any, None, Some(&["X"]));
+}
+
+#[test]
+fn test_frame_fillna_value() {
+    let df = frame();
+
+    let spec = vec![("X", Scalar::i64(0)), ("Z", Scalar::f64(1.5))];
+    let res = df.fillna(FillNA::Value(&spec), None);
+    let exp_values = vec![
+        array![1i64, 0, 3, 0, 5],
+        Array::StringArray(NullVec::with_mask(vec!["a".to_string(), "".to_string(), "c".to_string(),
+                                                   "".to_string(), "e".to_string()],
+                                              Some(vec![false, true, false, false, false]))),
+        array![1.5, 2., 1.5, 1.5, 5.],
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30, 40, 50], vec!["X", "Y", "Z"]);
+    assert_eq!(res, exp);
+
+    let res = df.fillna(FillNA::Value(&spec), Some(1));
+    let exp = Array::Float64Array(NullVec::with_mask(vec![1.5, 2., 0., 0., 5.],
+                                                     Some(vec![false, false, true, true, false])));
+    assert_eq!(res.get(&"Z").values.as_ref(), &exp);
+}
+
+#[test]
+#[should_panic]
+fn test_frame_fillna_dtype_mismatch_panic() {
+    let df = frame();
+    let spec = vec![("X", Scalar::f64(1.5))];
+    df.fillna(FillNA::Value(&spec), None);
+}
+
+#[test]
+fn test_frame_try_fillna() {
+    let df = frame();
+
+    let spec = vec![("X", Scalar::f64(1.5))];
+    let exp = Error::DtypeMismatch("i64".to_string(), "f64".to_string());
+    assert_eq!(df.try_fillna(FillNA::Value(&spec), None).err(), Some(exp));
+
+    let spec = vec![("X", Scalar::Null)];
+    assert!(df.try_fillna(FillNA::Value(&spec), None).is_err());
+
+    let spec = vec![("W", Scalar::i64(0))];
+    assert_eq!(df.try_fillna(FillNA::Value(&spec), None).err(), Some(Error::LabelNotFound));
+
+    let spec = vec![("X", Scalar::i64(0))];
+    assert_eq!(df.try_fillna(FillNA::Value(&spec), None).unwrap(),
+               df.fillna(FillNA::Value(&spec), None));
+}
+
+#[test]
+fn test_frame_fillna_propagate() {
+    let df = frame();
+
+    let res = df.fillna(FillNA::Ffill, None);
+    let exp_values = vec![
+        array![1i64, 1, 3, 3, 5],
+        array!["a".to_string(), "a".to_string(), "c".to_string(), "".to_string(), "e".to_string()],
+        Array::Float64Array(NullVec::with_mask(vec![0., 2., 2., 2., 5.],
+                                               Some(vec![true, false, false, false, false]))),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30, 40, 50], vec!["X", "Y", "Z"]);
+    assert_eq!(res, exp);
+
+    let res = df.fillna(FillNA::Bfill, Some(1));
+    let exp = Array::Float64Array(NullVec::with_mask(vec![2., 2., 0., 5., 5.],
+                                                     Some(vec![false, false, true, false, false])));
+    assert_eq!(res.get(&"Z").values.as_ref(), &exp);
+}
+
+#[test]
+fn test_frame_interpolate() {
+    let values = vec![
+        Array::Int64Array(NullVec::with_mask(vec![1, 0, 0, 4], Some(vec![false, true, true, false]))),
+        array!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()],
+        Array::Float64Array(NullVec::with_mask(vec![0., 2., 0., 3.], Some(vec![true, false, true, false]))),
+    ];
+    let df = DataFrame::from_vec(values, vec![10, 20, 30, 40], vec!["X", "Y", "Z"]);
+
+    let res = df.interpolate(Interpolation::Linear);
+    let exp_values = vec![
+        array![1., 2., 3., 4.],
+        array!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()],
+        Array::Float64Array(NullVec::with_mask(vec![0., 2., 2.5, 3.], Some(vec![true, false, false, false]))),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30, 40], vec!["X", "Y", "Z"]);
+    assert_eq!(res, exp);
+
+    let res = df.interpolate(Interpolation::Nearest);
+    assert_eq!(res.get(&"X").values.as_ref(), &array![1., 1., 4., 4.]);
+}
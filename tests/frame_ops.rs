@@ -0,0 +1,185 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+fn frame<'a>() -> DataFrame<'a, 'a, 'a, i64, &'a str> {
+    let values = vec![
+        array![1i64, 2, 3],
+        Array::Float64Array(NullVec::with_mask(vec![1.5, 0., 3.5],
+                                               Some(vec![false, true, false]))),
+    ];
+    DataFrame::from_vec(values, vec![10, 20, 30], vec!["X", "Y"])
+}
+
+#[test]
+fn test_frame_ops_scalar() {
+    let df = frame();
+
+    let exp_values = vec![
+        array![3i64, 4, 5],
+        Array::Float64Array(NullVec::with_mask(vec![3.5, 0., 5.5],
+                                               Some(vec![false, true, false]))),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30], vec!["X", "Y"]);
+    assert_eq!(&df + 2, exp);
+
+    // integer is promoted to float
+    let exp_values = vec![
+        array![0.5, 1., 1.5],
+        Array::Float64Array(NullVec::with_mask(vec![0.75, 0., 1.75],
+                                               Some(vec![false, true, false]))),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30], vec!["X", "Y"]);
+    assert_eq!(df / 2., exp);
+}
+
+#[test]
+fn test_frame_ops_int_division_by_zero() {
+    let df = DataFrame::from_vec(vec![array![4i64, 5]], vec![1, 2], vec!["X"]);
+    let other = DataFrame::from_vec(vec![array![2i64, 0]], vec![1, 2], vec!["X"]);
+
+    let exp_values = vec![
+        Array::Int64Array(NullVec::with_mask(vec![2, 0], Some(vec![false, true]))),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![1, 2], vec!["X"]);
+    assert_eq!(&df / &other, exp);
+}
+
+#[test]
+fn test_frame_ops_int_dtype() {
+    // the same dtype is kept
+    let df = DataFrame::from_vec(vec![array![1i32, 2]], vec![1, 2], vec!["X"]);
+    let exp = DataFrame::from_vec(vec![array![2i32, 4]], vec![1, 2], vec!["X"]);
+    assert_eq!(&df + &df, exp);
+
+    // integer scalar keeps the dtype of the column
+    let exp = DataFrame::from_vec(vec![array![3i32, 4]], vec![1, 2], vec!["X"]);
+    assert_eq!(&df + 2, exp);
+
+    // different dtypes are computed as i64
+    let other = DataFrame::from_vec(vec![array![10u8, 20]], vec![1, 2], vec!["X"]);
+    let exp = DataFrame::from_vec(vec![array![11i64, 22]], vec![1, 2], vec!["X"]);
+    assert_eq!(&df + &other, exp);
+
+    // u64 beyond i64::MAX is kept
+    let df = DataFrame::from_vec(vec![array![u64::MAX - 1, 1]], vec![1, 2], vec!["X"]);
+    let exp = DataFrame::from_vec(vec![array![u64::MAX, 2]], vec![1, 2], vec!["X"]);
+    assert_eq!(&df + 1, exp);
+
+    // out of range of the dtype is null
+    let exp_values = vec![
+        Array::UInt64Array(NullVec::with_mask(vec![u64::MAX - 3, 0], Some(vec![false, true]))),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![1, 2], vec!["X"]);
+    assert_eq!(&df - 2, exp);
+}
+
+#[test]
+fn test_frame_ops_frame_aligned() {
+    let df = frame();
+    let other = DataFrame::from_vec(vec![array![10i64, 20, 30], array![1.5, 2.5, 3.5]],
+                                    vec![40, 20, 10],
+                                    vec!["Z", "X"]);
+
+    let res = &df - &other;
+    let exp_values = vec![
+        Array::Float64Array(NullVec::with_mask(vec![-2.5, -0.5, 0., 0.],
+                                               Some(vec![false, false, true, true]))),
+        Array::Float64Array(NullVec::with_mask(vec![0., 0., 0., 0.],
+                                               Some(vec![true, true, true, true]))),
+        Array::Int64Array(NullVec::with_mask(vec![0, 0, 0, 0],
+                                             Some(vec![true, true, true, true]))),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30, 40], vec!["X", "Y", "Z"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_frame_ops_series_broadcast() {
+    let df = frame();
+
+    // aligned with columns
+    let s = Series::<i64, &str>::new(vec![10, 100], vec!["Y", "X"]);
+    let res = &df * &s;
+    let exp_values = vec![
+        array![100i64, 200, 300],
+        Array::Float64Array(NullVec::with_mask(vec![15., 0., 35.],
+                                               Some(vec![false, true, false]))),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30], vec!["X", "Y"]);
+    assert_eq!(res, exp);
+
+    // aligned with index
+    let s = Series::<i64, i64>::new(vec![1, 2, 3], vec![30, 20, 10]);
+    let res = &df + ColumnWise(&s);
+    let exp_values = vec![
+        array![4i64, 4, 4],
+        Array::Float64Array(NullVec::with_mask(vec![4.5, 0., 4.5],
+                                               Some(vec![false, true, false]))),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30], vec!["X", "Y"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_frame_ops_logical() {
+    let df = DataFrame::from_vec(vec![array![true, false, true]], vec![1, 2, 3], vec!["X"]);
+    let other = DataFrame::from_vec(vec![array![true, true, false]], vec![1, 2, 3], vec!["X"]);
+
+    let exp = DataFrame::from_vec(vec![array![true, false, false]], vec![1, 2, 3], vec!["X"]);
+    assert_eq!(&df & &other, exp);
+    let exp = DataFrame::from_vec(vec![array![false, true, true]], vec![1, 2, 3], vec!["X"]);
+    assert_eq!(df ^ &other, exp);
+}
+
+#[test]
+fn test_frame_compare() {
+    let df = frame();
+
+    let exp = DataFrame::from_vec(vec![array![false, true, true], array![false, false, true]],
+                                  vec![10, 20, 30],
+                                  vec!["X", "Y"]);
+    assert_eq!(df.gt(1.5), exp);
+
+    let exp = DataFrame::from_vec(vec![array![true, false, false], array![true, false, false]],
+                                  vec![10, 20, 30],
+                                  vec!["X", "Y"]);
+    assert_eq!(df.le(1.5), exp);
+
+    let exp = DataFrame::from_vec(vec![array![false, true, false], array![false, false, false]],
+                                  vec![10, 20, 30],
+                                  vec!["X", "Y"]);
+    assert_eq!(df.elem_eq(2), exp);
+
+    // PartialEq is not shadowed
+    assert!(df.eq(&frame()));
+}
+
+#[test]
+fn test_frame_ops_non_numeric() {
+    let df = DataFrame::from_vec(vec![array![1i64, 2], array!["a".to_string(), "b".to_string()]],
+                                 vec![1, 2],
+                                 vec!["X", "Y"]);
+    let res = df.try_add(1);
+    let exp = Error::InvalidOperation(
+        "unable to apply add to non-numeric column (dtype: str)".to_string(),
+    );
+    assert_eq!(res, Err(exp));
+
+    let res = df.try_lt(1);
+    assert!(res.is_err());
+
+    let df = frame();
+    let res = df.try_bitand(1.);
+    let exp = Error::InvalidOperation("unable to apply bitand to float column".to_string());
+    assert_eq!(res, Err(exp));
+}
+
+#[test]
+#[should_panic]
+fn test_frame_ops_non_numeric_panic() {
+    let df = DataFrame::from_vec(vec![array!["a".to_string(), "b".to_string()]],
+                                 vec![1, 2],
+                                 vec!["X"]);
+    let _ = df + 1;
+}
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 #[macro_use]
 extern crate brassfibre;
 use brassfibre::prelude::*;
@@ -116,3 +118,70 @@ fn test_block_join() {
 
     assert_eq!(res, exp);
 }
+
+#[test]
+fn test_dataframe_join_left() {
+    let values1 = vec![array![1, 2, 3], array![4.1, 5.1, 6.1]];
+    let df1 = DataFrame::from_vec(values1, vec!["A", "B", "C"], vec!["X", "Y"]);
+
+    let values2 = vec![array![4, 5, 6], array!["a", "b", "c"]];
+    let df2 = DataFrame::from_vec(values2, vec!["C", "A", "D"], vec!["X2", "Y2"]);
+    let res = df1.join_left(&df2);
+
+    let exp_values = vec![
+        array![1, 2, 3],
+        array![4.1, 5.1, 6.1],
+        Array::Int32Array(NullVec::with_mask(vec![5, 0, 4], Some(vec![false, true, false]))),
+        Array::StringArray(NullVec::with_mask(
+            vec!["b".to_string(), "".to_string(), "a".to_string()],
+            Some(vec![false, true, false]),
+        )),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec!["A", "B", "C"], vec!["X", "Y", "X2", "Y2"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_dataframe_join_right() {
+    let values1 = vec![array![1, 2, 3], array![4.1, 5.1, 6.1]];
+    let df1 = DataFrame::from_vec(values1, vec!["A", "B", "C"], vec!["X", "Y"]);
+
+    let values2 = vec![array![4, 5, 6]];
+    let df2 = DataFrame::from_vec(values2, vec!["C", "A", "D"], vec!["X2"]);
+    let res = df1.join_right(&df2);
+
+    let exp_values = vec![
+        Array::Int32Array(NullVec::with_mask(vec![3, 1, 0], Some(vec![false, false, true]))),
+        array![6.1, 4.1, f64::NAN],
+        array![4, 5, 6],
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec!["C", "A", "D"], vec!["X", "Y", "X2"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_dataframe_join_outer() {
+    let values1 = vec![array![1, 2, 3], array![4.1, 5.1, 6.1]];
+    let df1 = DataFrame::from_vec(values1, vec!["A", "B", "C"], vec!["X", "Y"]);
+
+    let values2 = vec![array![4, 5, 6]];
+    let df2 = DataFrame::from_vec(values2, vec!["C", "A", "D"], vec!["X2"]);
+    let res = df1.join_outer(&df2);
+
+    let exp_values = vec![
+        Array::Int32Array(NullVec::with_mask(vec![1, 2, 3, 0], Some(vec![false, false, false, true]))),
+        array![4.1, 5.1, 6.1, f64::NAN],
+        Array::Int32Array(NullVec::with_mask(vec![5, 0, 4, 6], Some(vec![false, true, false, false]))),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec!["A", "B", "C", "D"], vec!["X", "Y", "X2"]);
+    assert_eq!(res, exp);
+
+    // no common index
+    let values3 = vec![array![7, 8]];
+    let df3 = DataFrame::from_vec(values3, vec!["E", "F"], vec!["X3"]);
+    let res = df1.join_outer(&df3);
+    assert_eq!(res.index, Cow::Owned(Indexer::new(vec!["A", "B", "C", "E", "F"])));
+    assert_eq!(res.get(&"X").iloc(&3), Scalar::Null);
+    assert_eq!(res.get(&"X3").iloc(&0), Scalar::Null);
+    assert_eq!(res.get(&"X3").iloc(&3), Scalar::i32(7));
+}
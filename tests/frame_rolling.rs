@@ -0,0 +1,58 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+#[test]
+fn test_frame_rolling() {
+    let values = vec![
+        array![1i64, 2, 3, 4],
+        array!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()],
+        Array::Float64Array(NullVec::with_mask(vec![1.5, 0., 3.5, 4.5],
+                                               Some(vec![false, true, false, false]))),
+    ];
+    let df = DataFrame::from_vec(values, vec![10, 20, 30, 40], vec!["X", "Y", "Z"]);
+    let r = df.rolling(2, 1, false);
+
+    let exp_values = vec![array![1., 3., 5., 7.], array![1.5, 1.5, 3.5, 8.]];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30, 40], vec!["X", "Z"]);
+    assert_eq!(r.sum(), exp);
+
+    let exp_values = vec![array![1., 2., 3., 4.], array![1.5, 1.5, 3.5, 4.5]];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30, 40], vec!["X", "Z"]);
+    assert_eq!(r.max(), exp);
+
+    let exp_values = vec![array![1., 2., 2., 2.], array![1., 1., 1., 2.]];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30, 40], vec!["X", "Z"]);
+    assert_eq!(r.count(), exp);
+
+    let res = r.apply(&|x: &Vec<f64>| x.len() as f64 * 10.);
+    let exp_values = vec![array![10., 20., 20., 20.], array![10., 10., 10., 20.]];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30, 40], vec!["X", "Z"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_frame_expanding_ewm() {
+    let values = vec![
+        array![1i64, 2, 3, 4],
+        array!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()],
+        Array::Float64Array(NullVec::with_mask(vec![1.5, 0., 3.5, 0.5],
+                                               Some(vec![false, true, false, false]))),
+    ];
+    let df = DataFrame::from_vec(values, vec![10, 20, 30, 40], vec!["X", "Y", "Z"]);
+
+    let r = df.expanding(1);
+    let exp_values = vec![array![1., 1., 1., 1.], array![1.5, 1.5, 1.5, 0.5]];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30, 40], vec!["X", "Z"]);
+    assert_eq!(r.min(), exp);
+    let exp_values = vec![array![1., 1.5, 2., 2.5], array![1.5, 1.5, 2.5, 11. / 6.]];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30, 40], vec!["X", "Z"]);
+    assert_eq!(r.mean(), exp);
+
+    let values = vec![array![1i64, 2, 3, 4], array![2., 4., 8., 16.]];
+    let df = DataFrame::from_vec(values, vec![10, 20, 30, 40], vec!["X", "Z"]);
+    let r = df.ewm(Decay::Alpha(0.5), false);
+    let exp_values = vec![array![1., 1.5, 2.25, 3.125], array![2., 3., 5.5, 10.75]];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30, 40], vec!["X", "Z"]);
+    assert_eq!(r.mean(), exp);
+}
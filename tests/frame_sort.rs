@@ -0,0 +1,53 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+#[test]
+fn test_frame_sort_index() {
+    let values = vec![array![1i64, 2, 3, 4], array!["a".to_string(), "b".to_string(),
+                                                     "c".to_string(), "d".to_string()]];
+    let df = DataFrame::from_vec(values, vec![30, 10, 40, 20], vec!["X", "Y"]);
+
+    let exp_values = vec![array![2i64, 4, 1, 3], array!["b".to_string(), "d".to_string(),
+                                                         "a".to_string(), "c".to_string()]];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30, 40], vec!["X", "Y"]);
+    assert_eq!(df.sort_index(), exp);
+}
+
+#[test]
+fn test_frame_sort_values_multi_keys() {
+    let values = vec![
+        array!["b".to_string(), "a".to_string(), "b".to_string(), "a".to_string(), "b".to_string()],
+        array![true, false, false, true, true],
+        array![1.5, 2.5, 3.5, 4.5, 5.5],
+    ];
+    let df = DataFrame::from_vec(values, vec![10, 20, 30, 40, 50], vec!["X", "Y", "Z"]);
+
+    let res = df.sort_values(&["X", "Y"], &[true, false], NaPosition::Last);
+    // stable within equal keys (10 and 50)
+    let exp_values = vec![
+        array!["a".to_string(), "a".to_string(), "b".to_string(), "b".to_string(), "b".to_string()],
+        array![true, false, true, true, false],
+        array![4.5, 2.5, 1.5, 5.5, 3.5],
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![40, 20, 10, 50, 30], vec!["X", "Y", "Z"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_frame_sort_values_na_position() {
+    let values = vec![
+        Array::Float64Array(NullVec::with_mask(vec![2., 0., 1., 3.],
+                                               Some(vec![false, true, false, false]))),
+        array![1i64, 2, 3, 4],
+    ];
+    let df = DataFrame::from_vec(values, vec!["a", "b", "c", "d"], vec!["X", "Y"]);
+
+    let res = df.sort_values(&["X"], &[false], NaPosition::Last);
+    assert_eq!(res.index, Indexer::new(vec!["d", "a", "c", "b"]).into());
+    assert_eq!(res.values[1].as_ref(), &array![4i64, 1, 3, 2]);
+
+    let res = df.sort_values(&["X"], &[true], NaPosition::First);
+    assert_eq!(res.index, Indexer::new(vec!["b", "c", "a", "d"]).into());
+    assert_eq!(res.iloc(&0).values[0], Scalar::Null);
+}
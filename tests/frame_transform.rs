@@ -0,0 +1,77 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+fn frame<'a>() -> DataFrame<'a, 'a, 'a, i64, &'a str> {
+    let values = vec![
+        array![1i64, 2, 3, 4],
+        array!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()],
+        Array::Float64Array(NullVec::with_mask(vec![1.5, 0., 3.5, 0.5],
+                                               Some(vec![false, true, false, false]))),
+    ];
+    DataFrame::from_vec(values, vec![10, 20, 30, 40], vec!["X", "Y", "Z"])
+}
+
+#[test]
+fn test_frame_cumulative() {
+    let df = frame();
+
+    // integer dtype is kept
+    let exp_values = vec![
+        array![1i64, 3, 6, 10],
+        Array::Float64Array(NullVec::with_mask(vec![1.5, 0., 5., 5.5],
+                                               Some(vec![false, true, false, false]))),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30, 40], vec!["X", "Z"]);
+    assert_eq!(df.cumsum(), exp);
+
+    let exp_values = vec![
+        array![1i64, 1, 1, 1],
+        Array::Float64Array(NullVec::with_mask(vec![1.5, 0., 1.5, 0.5],
+                                               Some(vec![false, true, false, false]))),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30, 40], vec!["X", "Z"]);
+    assert_eq!(df.cummin(), exp);
+
+    let df = DataFrame::from_vec(vec![array![100u8, 2, 3]], vec![1, 2, 3], vec!["X"]);
+    let exp_values = vec![
+        Array::UInt8Array(NullVec::with_mask(vec![100, 200, 0], Some(vec![false, false, true]))),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![1, 2, 3], vec!["X"]);
+    assert_eq!(df.cumprod(), exp);
+}
+
+#[test]
+fn test_frame_shift_diff() {
+    let df = frame();
+
+    let res = df.shift(1);
+    let exp_values = vec![
+        Array::Int64Array(NullVec::with_mask(vec![0, 1, 2, 3], Some(vec![true, false, false, false]))),
+        Array::StringArray(NullVec::with_mask(vec!["".to_string(), "a".to_string(), "b".to_string(),
+                                                   "c".to_string()],
+                                              Some(vec![true, false, false, false]))),
+        Array::Float64Array(NullVec::with_mask(vec![0., 1.5, 0., 3.5],
+                                               Some(vec![true, false, true, false]))),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30, 40], vec!["X", "Y", "Z"]);
+    assert_eq!(res, exp);
+
+    let exp_values = vec![
+        Array::Float64Array(NullVec::with_mask(vec![0., 1., 1., 1.],
+                                               Some(vec![true, false, false, false]))),
+        Array::Float64Array(NullVec::with_mask(vec![0., 0., 0., -3.],
+                                               Some(vec![true, true, true, false]))),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30, 40], vec!["X", "Z"]);
+    assert_eq!(df.diff(1), exp);
+
+    let exp_values = vec![
+        Array::Float64Array(NullVec::with_mask(vec![0., 1., 0.5, 4. / 3. - 1.],
+                                               Some(vec![true, false, false, false]))),
+        Array::Float64Array(NullVec::with_mask(vec![0., 0., 0., 0.5 / 3.5 - 1.],
+                                               Some(vec![true, true, true, false]))),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec![10, 20, 30, 40], vec!["X", "Z"]);
+    assert_eq!(df.pct_change(1), exp);
+}
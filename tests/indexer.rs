@@ -119,6 +119,59 @@ fn test_index_push() {
     assert_eq!(idx.get_loc(&"E"), 4);
 }
 
+#[test]
+fn test_index_duplicates() {
+    let values: Vec<&str> = vec!["A", "B", "A", "C", "A"];
+    let idx = Indexer::<&str>::new(values);
+
+    assert!(!idx.is_unique());
+    assert!(idx.contains(&"A"));
+    assert_eq!(idx.get_loc(&"B"), 1);
+    assert_eq!(idx.get_all_locs(&"A"), vec![0, 2, 4]);
+    assert_eq!(idx.get_all_locs(&"C"), vec![3]);
+    assert_eq!(idx.get_locs(&["C", "A"]), vec![3, 0, 2, 4]);
+
+    let idx = Indexer::<&str>::new(vec!["A", "B", "C"]);
+    assert!(idx.is_unique());
+}
+
+#[test]
+#[should_panic(expected = "label is duplicated")]
+fn test_index_duplicates_get_loc() {
+    let idx = Indexer::<&str>::new(vec!["A", "B", "A"]);
+    idx.get_loc(&"A");
+}
+
+#[test]
+#[should_panic(expected = "label not found")]
+fn test_index_get_loc_missing() {
+    let idx = Indexer::<&str>::new(vec!["A", "B", "A"]);
+    idx.get_loc(&"C");
+}
+
+#[test]
+#[should_panic(expected = "label not found")]
+fn test_index_get_all_locs_missing() {
+    let idx = Indexer::<&str>::new(vec!["A", "B", "A"]);
+    idx.get_all_locs(&"C");
+}
+
+#[test]
+fn test_index_push_duplicates() {
+    let mut idx = Indexer::<i64>::new(vec![1, 2, 3]);
+    assert!(idx.is_unique());
+
+    idx.push(2);
+    assert!(!idx.is_unique());
+    assert_eq!(idx.get_all_locs(&2), vec![1, 3]);
+
+    // push before initializing hashtables
+    let mut idx = Indexer::<i64>::new(vec![1, 2, 3]);
+    idx.push(1);
+    assert_eq!(idx.get_loc(&3), 2);
+    assert_eq!(idx.get_all_locs(&1), vec![0, 3]);
+}
+
 #[test]
 fn test_index_iloc_ilocs() {
     let values: Vec<i64> = vec![1, 2, 3];
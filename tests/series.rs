@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+#[macro_use]
 extern crate brassfibre;
 use brassfibre::prelude::*;
 
@@ -206,6 +207,81 @@ fn test_series_reindex_by_index_panic() {
     s.reindex_by_index(&vec![1, 3, 10]);
 }
 
+#[test]
+fn test_series_locs_duplicates() {
+    let s = Series::<i64, &str>::new(vec![1, 2, 3, 4], vec!["A", "B", "A", "C"]);
+    assert!(!s.index.is_unique());
+    assert_eq!(s.loc(&"B"), 2);
+
+    let res = s.locs(&["A"]);
+    assert_eq!(res, Series::new(vec![1, 3], vec!["A", "A"]));
+
+    let res = s.locs(&["C", "A"]);
+    assert_eq!(res, Series::new(vec![4, 1, 3], vec!["C", "A", "A"]));
+}
+
+#[test]
+fn test_series_loc_all_duplicates() {
+    let s = Series::<i64, &str>::new(vec![1, 2, 3, 4], vec!["A", "B", "A", "C"]);
+
+    let res = s.loc_all(&"A");
+    assert_eq!(res, Series::new(vec![1, 3], vec!["A", "A"]));
+
+    let res = s.loc_all(&"B");
+    assert_eq!(res, Series::new(vec![2], vec!["B"]));
+}
+
+#[test]
+fn test_series_drop_duplicates() {
+    let s = Series::<&str, i64>::new(vec!["a", "b", "a", "c", "b"], vec![1, 2, 3, 4, 5]);
+
+    let res = s.drop_duplicates(Duplicates::First);
+    assert_eq!(res, Series::new(vec!["a", "b", "c"], vec![1, 2, 4]));
+
+    let res = s.drop_duplicates(Duplicates::Last);
+    assert_eq!(res, Series::new(vec!["a", "c", "b"], vec![3, 4, 5]));
+
+    let res = s.drop_duplicates(Duplicates::None);
+    assert_eq!(res, Series::new(vec!["c"], vec![4]));
+
+    let exp = Series::new(vec![false, false, true, false, true], vec![1, 2, 3, 4, 5]);
+    assert_eq!(s.duplicated(Duplicates::First), exp);
+}
+
+#[test]
+fn test_series_factorize() {
+    let s = Series::<&str, i64>::new(vec!["b", "c", "b", "a"], vec![1, 2, 3, 4]);
+
+    let (codes, uniques) = s.factorize(false);
+    assert_eq!(codes, vec![0, 1, 0, 2]);
+    assert_eq!(uniques, vec!["b", "c", "a"]);
+
+    let (codes, uniques) = s.factorize(true);
+    assert_eq!(codes, vec![1, 2, 1, 0]);
+    assert_eq!(uniques, vec!["a", "b", "c"]);
+}
+
+#[test]
+fn test_series_factorize_float() {
+    let s = Series::<f64, i64>::new(vec![2.5, 1.5, 2.5, 0.5], vec![1, 2, 3, 4]);
+
+    let (codes, uniques) = s.factorize(false);
+    assert_eq!(codes, vec![0, 1, 0, 2]);
+    assert_eq!(uniques, vec![2.5, 1.5, 0.5]);
+
+    let (codes, uniques) = s.factorize(true);
+    assert_eq!(codes, vec![2, 1, 2, 0]);
+    assert_eq!(uniques, vec![0.5, 1.5, 2.5]);
+
+    // null is not included in uniques
+    let s = Series::<f64, i64>::with_mask(vec![2.5, 0., 2.5, 0.5],
+                                          vec![1, 2, 3, 4],
+                                          vec![false, true, false, false]);
+    let (codes, uniques) = s.factorize(true);
+    assert_eq!(codes, vec![1, USIZE_MISSING, 1, 0]);
+    assert_eq!(uniques, vec![0.5, 2.5]);
+}
+
 #[test]
 fn test_series_append() {
     let values: Vec<f64> = vec![1., 2., 3., 4., 5.];
@@ -226,6 +302,46 @@ fn test_series_append() {
     assert_eq!(res, exp);
 }
 
+#[test]
+fn test_series_join_inner() {
+    let s1 = Series::<i64, &str>::new(vec![1, 2, 3], vec!["A", "B", "C"]);
+    let s2 = Series::<i64, &str>::new(vec![4, 5, 6], vec!["C", "A", "D"]);
+
+    let res = s1.join_inner(&s2);
+    let exp = Block::from_vec(vec![vec![1, 3], vec![5, 4]], vec!["A", "C"], vec![0, 1]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_series_join_nullable() {
+    let s1 = Series::<i64, &str>::new(vec![1, 2, 3], vec!["A", "B", "C"]);
+    let s2 = Series::<i64, &str>::new(vec![4, 5, 6], vec!["C", "A", "D"]);
+
+    let res = s1.join_left(&s2);
+    let exp_values = vec![
+        array![1i64, 2, 3],
+        Array::Int64Array(NullVec::with_mask(vec![5, 0, 4], Some(vec![false, true, false]))),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec!["A", "B", "C"], vec![0, 1]);
+    assert_eq!(res, exp);
+
+    let res = s1.join_right(&s2);
+    let exp_values = vec![
+        Array::Int64Array(NullVec::with_mask(vec![3, 1, 0], Some(vec![false, false, true]))),
+        array![4i64, 5, 6],
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec!["C", "A", "D"], vec![0, 1]);
+    assert_eq!(res, exp);
+
+    let res = s1.join_outer(&s2);
+    let exp_values = vec![
+        Array::Int64Array(NullVec::with_mask(vec![1, 2, 3, 0], Some(vec![false, false, false, true]))),
+        Array::Int64Array(NullVec::with_mask(vec![5, 0, 4, 6], Some(vec![false, true, false, false]))),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec!["A", "B", "C", "D"], vec![0, 1]);
+    assert_eq!(res, exp);
+}
+
 #[test]
 fn test_series_into_iter() {
     let s: Series<i64, i64> = Series::new(vec![1, 2, 3], vec![10, 20, 30]);
@@ -252,3 +368,15 @@ fn test_series_from_iter() {
     let exp: Series<i64, usize> = Series::new(vec![3, 4, 5], vec![0, 1, 2]);
     assert_eq!(s, exp);
 }
+
+#[test]
+fn test_series_sort_values_with() {
+    let s: Series<f64, &str> = Series::with_mask(vec![2.5, 0., 1.5], vec!["a", "b", "c"],
+                                                 vec![false, true, false]);
+
+    let res = s.sort_values_with(true, NaPosition::First);
+    let exp = Series::with_mask(vec![0., 1.5, 2.5], vec!["b", "c", "a"], vec![true, false, false]);
+    assert_eq!(res, exp);
+
+    assert_eq!(s.argsort(false, NaPosition::Last), vec![0, 2, 1]);
+}
@@ -0,0 +1,87 @@
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+#[test]
+fn test_series_add_aligned() {
+    let s = Series::<i64, &str>::new(vec![1, 2, 3], vec!["a", "b", "c"]);
+    let r = Series::<i64, &str>::new(vec![10, 20, 30], vec!["d", "c", "a"]);
+
+    let res = s.add_aligned(&r, None);
+    let exp = Series::<i64, &str>::with_mask(vec![31, 0, 23, 0],
+                                             vec!["a", "b", "c", "d"],
+                                             vec![false, true, false, true]);
+    assert_eq!(res, exp);
+
+    let res = s.add_aligned(&r, Some(0));
+    let exp = Series::<i64, &str>::new(vec![31, 2, 23, 10], vec!["a", "b", "c", "d"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_series_sub_aligned_null() {
+    let s = Series::<f64, &str>::with_mask(vec![1., 2., 3.],
+                                           vec!["a", "b", "c"],
+                                           vec![false, true, true]);
+    let r = Series::<f64, &str>::with_mask(vec![10., 20.],
+                                           vec!["c", "a"],
+                                           vec![true, false]);
+
+    // null is filled only when the other side is not null
+    let res = s.sub_aligned(&r, Some(5.));
+    let exp = Series::<f64, &str>::with_mask(vec![-19., 0., 0.],
+                                             vec!["a", "b", "c"],
+                                             vec![false, true, true]);
+    assert_eq!(res, exp);
+
+    let r = Series::<f64, &str>::new(vec![10., 20.], vec!["b", "d"]);
+    let res = s.sub_aligned(&r, Some(5.));
+    let exp = Series::<f64, &str>::with_mask(vec![-4., -5., 0., -15.],
+                                             vec!["a", "b", "c", "d"],
+                                             vec![false, false, true, false]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_series_div_aligned_int_null() {
+    // division by placeholders (missing label or null) must not panic
+    let s = Series::<i64, i64>::new(vec![6, 8, 9], vec![1, 2, 3]);
+    let r = Series::<i64, i64>::with_mask(vec![0, 2], vec![3, 2], vec![true, false]);
+
+    let res = s.div_aligned(&r, None);
+    let exp = Series::<i64, i64>::with_mask(vec![0, 4, 0], vec![1, 2, 3], vec![true, false, true]);
+    assert_eq!(res, exp);
+
+    let res = &s / &r;
+    assert_eq!(res, exp);
+
+    let res = s.div_aligned(&r, Some(1));
+    let exp = Series::<i64, i64>::new(vec![6, 4, 9], vec![1, 2, 3]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_series_mul_div_aligned() {
+    let s = Series::<f64, i64>::new(vec![1., 2., 3.], vec![1, 2, 3]);
+    let r = Series::<f64, i64>::new(vec![4., 5.], vec![3, 2]);
+
+    let res = s.mul_aligned(&r, Some(1.));
+    let exp = Series::<f64, i64>::new(vec![1., 10., 12.], vec![1, 2, 3]);
+    assert_eq!(res, exp);
+
+    let res = s.div_aligned(&r, None);
+    let exp = Series::<f64, i64>::with_mask(vec![0., 0.4, 0.75],
+                                            vec![1, 2, 3],
+                                            vec![true, false, false]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_series_aligned_same_index() {
+    // duplicated labels are not joined if indexes are identical
+    let s = Series::<i64, i64>::new(vec![1, 2, 3], vec![1, 1, 2]);
+    let r = Series::<i64, i64>::new(vec![10, 20, 30], vec![1, 1, 2]);
+
+    let res = s.add_aligned(&r, None);
+    let exp = Series::<i64, i64>::new(vec![11, 22, 33], vec![1, 1, 2]);
+    assert_eq!(res, exp);
+}
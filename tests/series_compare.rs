@@ -0,0 +1,58 @@
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+#[test]
+fn test_series_compare_scalar() {
+    let s = Series::<i64, &str>::new(vec![1, 5, 3, 4], vec!["a", "b", "c", "d"]);
+    let index = vec!["a", "b", "c", "d"];
+
+    assert_eq!(s.gt(&3), Series::new(vec![false, true, false, true], index.clone()));
+    assert_eq!(s.ge(&3), Series::new(vec![false, true, true, true], index.clone()));
+    assert_eq!(s.lt(&3), Series::new(vec![true, false, false, false], index.clone()));
+    assert_eq!(s.le(&3), Series::new(vec![true, false, true, false], index.clone()));
+    assert_eq!(s.elem_eq(&3), Series::new(vec![false, false, true, false], index.clone()));
+    assert_eq!(s.elem_ne(&3), Series::new(vec![true, true, false, true], index.clone()));
+
+    // PartialEq is not shadowed
+    assert!(s.eq(&s.clone()));
+    assert!(!s.ne(&s.clone()));
+}
+
+#[test]
+fn test_series_isin_between() {
+    let s = Series::<&str, usize>::from_vec(vec!["x", "y", "z", "x"]);
+    assert_eq!(s.isin(&["x", "z"]), Series::<bool, usize>::from_vec(vec![true, false, true, true]));
+
+    let s = Series::<f64, usize>::from_vec(vec![1.5, 2., 3.5, 4.]);
+    assert_eq!(s.between(&2., &4.), Series::<bool, usize>::from_vec(vec![false, true, true, true]));
+}
+
+#[test]
+fn test_series_compare_null() {
+    let s = Series::<i64, usize>::with_mask(vec![1, 5, 3], vec![0, 1, 2], vec![false, true, false]);
+    assert_eq!(s.gt(&0), Series::<bool, usize>::from_vec(vec![true, false, true]));
+    assert_eq!(s.elem_ne(&1), Series::<bool, usize>::from_vec(vec![false, false, true]));
+    assert_eq!(s.isin(&[5]), Series::<bool, usize>::from_vec(vec![false, false, false]));
+
+    let s = Series::<f64, usize>::from_vec(vec![1., f64::NAN]);
+    assert_eq!(s.elem_ne(&1.), Series::<bool, usize>::from_vec(vec![false, true]));
+}
+
+#[test]
+fn test_series_combine_masks() {
+    let s = Series::<i64, &str>::new(vec![1, 5, 3, 4], vec!["a", "b", "c", "d"]);
+    let index = vec!["a", "b", "c", "d"];
+
+    let res = s.gt(&1) & s.lt(&5);
+    assert_eq!(res, Series::new(vec![false, false, true, true], index.clone()));
+
+    let res = s.elem_eq(&1) | s.elem_eq(&5);
+    assert_eq!(res, Series::new(vec![true, true, false, false], index.clone()));
+
+    let res = !s.gt(&3);
+    assert_eq!(res, Series::new(vec![true, false, true, false], index.clone()));
+
+    let mask = s.ge(&4);
+    let res = !&mask;
+    assert_eq!(res, Series::new(vec![true, false, true, false], index.clone()));
+}
@@ -58,3 +58,179 @@ fn test_series_agg_mean_integer_grouper() {
     let exp: Series<f64, i64> = Series::new(vec![2.0, 4.5], vec![1, 2]);
     assert_eq!(sum, exp);
 }
+
+#[test]
+fn test_series_groupby_agg() {
+    let values: Vec<i64> = vec![1, 2, 3, 4, 5, 2];
+    let s = Series::<i64, usize>::from_vec(values);
+    let sg = s.groupby(&["A", "B", "A", "B", "B", "A"]);
+
+    let double_max = |x: &Array| Scalar::i64(i64::from(x.max().as_i64()) * 2);
+    let res = sg.agg(&[AggFunc::Sum, AggFunc::Mean, AggFunc::NUnique, AggFunc::Last,
+                       AggFunc::Apply("double_max", &double_max)]);
+
+    let exp_values = vec![
+        Array::new(vec![6i64, 11]),
+        Array::new(vec![2., 11. / 3.]),
+        Array::new(vec![3usize, 3]),
+        Array::new(vec![2i64, 5]),
+        Array::new(vec![6i64, 10]),
+    ];
+    let exp = DataFrame::from_vec(exp_values, vec!["A", "B"],
+                                  vec!["sum", "mean", "nunique", "last", "double_max"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_series_groupby_transform() {
+    let values: Vec<f64> = vec![1., 2., 3., 4., 5., 6.];
+    let s = Series::<f64, i64>::new(values, vec![10, 20, 30, 40, 50, 60]);
+    let sg = s.groupby(&["A", "B", "A", "B", "A", "B"]);
+
+    let demean = |x: &Series<f64, i64>| {
+        let mean = x.mean();
+        x.values.iter().map(|v| v - mean).collect()
+    };
+    let res = sg.transform(&demean);
+    let exp: Series<f64, i64> = Series::new(vec![-2., -2., 0., 0., 2., 2.],
+                                            vec![10, 20, 30, 40, 50, 60]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_series_groupby_transform_null() {
+    let s = Series::<f64, i64>::with_mask(vec![1., 100., 3., 4.],
+                                          vec![10, 20, 30, 40],
+                                          vec![false, true, false, false]);
+    let sg = s.groupby(&["A", "A", "A", "B"]);
+
+    // placeholder behind null is ignored by mean, and the result is kept as null
+    let demean = |x: &Series<f64, i64>| {
+        let mean = x.mean();
+        x.values.iter().map(|v| v - mean).collect()
+    };
+    let res = sg.transform(&demean);
+    let exp = Series::<f64, i64>::with_mask(vec![-1., 98., 1., 0.],
+                                            vec![10, 20, 30, 40],
+                                            vec![false, true, false, false]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_series_groupby_agg_null() {
+    let s = Series::<i64, usize>::with_mask(vec![1, 100, 3, 4],
+                                            vec![0, 1, 2, 3],
+                                            vec![false, true, false, false]);
+    let sg = s.groupby(&["A", "A", "A", "B"]);
+
+    let res = sg.agg(&[AggFunc::Sum, AggFunc::Count]);
+    let exp_values = vec![Array::new(vec![4i64, 4]), Array::new(vec![2usize, 1])];
+    let exp = DataFrame::from_vec(exp_values, vec!["A", "B"], vec!["sum", "count"]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_series_groupby_filter() {
+    let values: Vec<i64> = vec![1, 2, 3, 4, 5, 6];
+    let s = Series::<i64, i64>::new(values, vec![10, 20, 30, 40, 50, 60]);
+    let sg = s.groupby(&["A", "B", "A", "C", "A", "C"]);
+
+    let res = sg.filter(&|x: &Series<i64, i64>| x.sum() > 5);
+    let exp: Series<i64, i64> = Series::new(vec![1, 3, 4, 5, 6], vec![10, 30, 40, 50, 60]);
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn test_series_groupby_sort_strategy() {
+    let values: Vec<i64> = vec![1, 2, 3, 4, 5, 6];
+    let s = Series::<i64, i64>::new(values, vec![10, 20, 30, 40, 50, 60]);
+    let keys = vec!["B", "A", "B", "C", "A", "B"];
+
+    let hg = GroupBy::with_strategy(&s, &keys, GroupStrategy::Hash);
+    let sg = GroupBy::with_strategy(&s, &keys, GroupStrategy::Sort);
+    assert_eq!(sg.groups(), vec!["A", "B", "C"]);
+    assert_eq!(sg.groups(), hg.groups());
+
+    // locations are in the same order as groups
+    let exp: Vec<&[usize]> = vec![&[1, 4], &[0, 2, 5], &[3]];
+    assert_eq!(sg.locations(), exp);
+    assert_eq!(hg.locations(), exp);
+
+    let exp: Series<i64, i64> = Series::new(vec![1, 3, 6], vec![10, 30, 60]);
+    assert_eq!(sg.get_group(&"B"), exp);
+
+    let exp: Series<i64, &str> = Series::new(vec![7, 10, 4], vec!["A", "B", "C"]);
+    assert_eq!(sg.sum(), exp);
+    assert_eq!(hg.sum(), exp);
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct UnorderedKey(&'static str);
+
+#[test]
+fn test_series_groupby_unsorted() {
+    let values: Vec<i64> = vec![1, 2, 3, 4, 5];
+    let s = Series::<i64, i64>::new(values, vec![10, 20, 30, 40, 50]);
+    let keys = vec![UnorderedKey("B"), UnorderedKey("C"), UnorderedKey("B"),
+                    UnorderedKey("A"), UnorderedKey("C")];
+    let sg = GroupBy::unsorted(&s, &keys);
+
+    assert_eq!(sg.groups(), vec![UnorderedKey("B"), UnorderedKey("C"), UnorderedKey("A")]);
+    let exp: Series<i64, i64> = Series::new(vec![2, 5], vec![20, 50]);
+    assert_eq!(sg.get_group(&UnorderedKey("C")), exp);
+    assert_eq!(sg.sum().values.into_owned(), vec![4, 7, 4]);
+}
+
+#[test]
+fn test_series_groupby_from_array() {
+    let values: Vec<i64> = vec![1, 2, 3, 4, 5];
+    let s = Series::<i64, i64>::new(values, vec![10, 20, 30, 40, 50]);
+    let keys = Array::Float64Array(NullVec::with_mask(vec![2.5, 0., 1.5, 2.5, 0.],
+                                                      Some(vec![false, true, false, false, true])));
+    let k1 = HashableScalar::new(&Scalar::f64(1.5));
+    let k2 = HashableScalar::new(&Scalar::f64(2.5));
+
+    let sg = GroupBy::from_array(&s, &keys, GroupByOptions::default());
+    let exp: Series<i64, HashableScalar> = Series::new(vec![3, 5], vec![k1.clone(), k2.clone()]);
+    assert_eq!(sg.sum(), exp);
+
+    let options = GroupByOptions { sort: false, dropna: true, ..GroupByOptions::default() };
+    let sg = GroupBy::from_array(&s, &keys, options);
+    let exp: Series<i64, HashableScalar> = Series::new(vec![5, 3], vec![k2.clone(), k1.clone()]);
+    assert_eq!(sg.sum(), exp);
+
+    let options = GroupByOptions { sort: true, dropna: false, ..GroupByOptions::default() };
+    let sg = GroupBy::from_array(&s, &keys, options);
+    let exp: Series<i64, HashableScalar> = Series::new(vec![3, 5, 7],
+                                                       vec![k1.clone(), k2.clone(),
+                                                            HashableScalar::Null]);
+    assert_eq!(sg.sum(), exp);
+
+    // strategy is honored regardless of sort
+    let options = GroupByOptions {
+        sort: false,
+        dropna: true,
+        strategy: GroupStrategy::Sort,
+    };
+    let sg = GroupBy::from_array(&s, &keys, options);
+    let exp: Series<i64, HashableScalar> = Series::new(vec![5, 3], vec![k2, k1]);
+    assert_eq!(sg.sum(), exp);
+}
+
+#[test]
+fn test_series_groupby_unsorted_dropna() {
+    let values: Vec<i64> = vec![1, 2, 3, 4, 5];
+    let s = Series::<i64, i64>::new(values, vec![10, 20, 30, 40, 50]);
+    let keys = vec![UnorderedKey("B"), UnorderedKey(""), UnorderedKey("B"),
+                    UnorderedKey("A"), UnorderedKey("")];
+    let sg = GroupBy::unsorted_dropna(&s, &keys, |k| k.0.is_empty());
+
+    assert_eq!(sg.groups(), vec![UnorderedKey("B"), UnorderedKey("A")]);
+    assert_eq!(sg.sum().values.into_owned(), vec![4, 4]);
+
+    // rows whose key is dropped are null
+    let res = sg.transform(&|x: &Series<i64, i64>| x.values.to_vec());
+    let exp = Series::<i64, i64>::with_mask(vec![1, 0, 3, 4, 0], vec![10, 20, 30, 40, 50],
+                                            vec![false, true, false, false, true]);
+    assert_eq!(res, exp);
+}
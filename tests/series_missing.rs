@@ -0,0 +1,141 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+#[test]
+fn test_series_isnull_notnull() {
+    let s = Series::<i64, &str>::with_mask(vec![1, 2, 3, 4], vec!["a", "b", "c", "d"],
+                                           vec![false, true, false, true]);
+    assert!(s.has_null());
+
+    let exp = Series::<bool, &str>::new(vec![false, true, false, true], vec!["a", "b", "c", "d"]);
+    assert_eq!(s.isnull(), exp);
+    let exp = Series::<bool, &str>::new(vec![true, false, true, false], vec!["a", "b", "c", "d"]);
+    assert_eq!(s.notnull(), exp);
+
+    // mask without null is normalized
+    let s = Series::<i64, &str>::with_mask(vec![1, 2], vec!["a", "b"], vec![false, false]);
+    assert!(!s.has_null());
+    assert_eq!(s, Series::<i64, &str>::new(vec![1, 2], vec!["a", "b"]));
+}
+
+#[test]
+fn test_series_eq_ignores_null_values() {
+    let s1 = Series::<i64, usize>::with_mask(vec![1, 2, 3], vec![0, 1, 2], vec![false, true, false]);
+    let s2 = Series::<i64, usize>::with_mask(vec![1, 9, 3], vec![0, 1, 2], vec![false, true, false]);
+    assert_eq!(s1, s2);
+
+    let s3 = Series::<i64, usize>::new(vec![1, 2, 3], vec![0, 1, 2]);
+    assert!(s1 != s3);
+}
+
+#[test]
+fn test_series_iloc_null_placeholder() {
+    let s = Series::<i64, &str>::with_mask(vec![1, 9, 3], vec!["a", "b", "c"],
+                                           vec![false, true, false]);
+    // null position returns the placeholder as is, mask tells it is null
+    assert_eq!(s.iloc(&1), 9);
+    assert_eq!(s.loc(&"b"), 9);
+    assert_eq!(s.try_loc(&"b"), Ok(9));
+    assert!(s.isnull().loc(&"b"));
+    assert_eq!(s.loc(&"c"), 3);
+}
+
+#[test]
+fn test_series_dropna() {
+    let s = Series::<i64, &str>::with_mask(vec![1, 2, 3, 4], vec!["a", "b", "c", "d"],
+                                           vec![false, true, false, true]);
+    let exp = Series::<i64, &str>::new(vec![1, 3], vec!["a", "c"]);
+    assert_eq!(s.dropna(), exp);
+
+    let s = Series::<i64, &str>::new(vec![1, 2], vec!["a", "b"]);
+    assert_eq!(s.dropna(), s);
+}
+
+#[test]
+fn test_series_fillna() {
+    let s = Series::<i64, &str>::with_mask(vec![1, 2, 3, 4, 5], vec!["a", "b", "c", "d", "e"],
+                                           vec![true, false, true, true, false]);
+
+    let exp = Series::<i64, &str>::new(vec![0, 2, 0, 0, 5], vec!["a", "b", "c", "d", "e"]);
+    assert_eq!(s.fillna(FillNA::Value(0)), exp);
+
+    let exp = Series::<i64, &str>::with_mask(vec![0, 2, 2, 2, 5], vec!["a", "b", "c", "d", "e"],
+                                             vec![true, false, false, false, false]);
+    assert_eq!(s.fillna(FillNA::Ffill), exp);
+
+    let exp = Series::<i64, &str>::new(vec![2, 2, 5, 5, 5], vec!["a", "b", "c", "d", "e"]);
+    assert_eq!(s.fillna(FillNA::Bfill), exp);
+}
+
+#[test]
+fn test_series_aggregation_skips_null() {
+    let s = Series::<i64, usize>::with_mask(vec![1, 100, 3, 4], vec![0, 1, 2, 3],
+                                            vec![false, true, false, false]);
+    assert_eq!(s.sum(), 8);
+    assert_eq!(s.count(), 3);
+    assert_eq!(s.mean(), 8. / 3.);
+    assert_eq!(s.max(), 4);
+    assert_eq!(s.min(), 1);
+
+    let res = s.value_counts();
+    assert_eq!(res.len(), 3);
+    assert_eq!(res.loc(&4), 1);
+}
+
+#[test]
+fn test_series_null_propagation() {
+    let s = Series::<i64, usize>::with_mask(vec![1, 2, 3], vec![0, 1, 2], vec![false, true, false]);
+
+    let res = s.reindex(&[2, 1]);
+    let exp = Series::<i64, usize>::with_mask(vec![3, 0], vec![2, 1], vec![false, true]);
+    assert_eq!(res, exp);
+
+    let r = Series::<i64, usize>::with_mask(vec![1, 2, 3], vec![0, 1, 2], vec![true, false, false]);
+    let exp = Series::<i64, usize>::with_mask(vec![0, 0, 6], vec![0, 1, 2], vec![true, true, false]);
+    assert_eq!(&s + &r, exp);
+
+    let exp = Series::<i64, usize>::with_mask(vec![11, 0, 13], vec![0, 1, 2], vec![false, true, false]);
+    assert_eq!(&s + 10, exp);
+
+    let res = s.append(&r);
+    assert_eq!(res.isnull().values.as_ref(), &vec![false, true, false, true, false, false]);
+}
+
+#[test]
+fn test_series_from_column_keeps_null() {
+    let values = vec![
+        array![1i64, 2, 3],
+        Array::Float64Array(NullVec::with_mask(vec![1.5, 0., 3.5], Some(vec![false, true, false]))),
+    ];
+    let df = DataFrame::from_vec(values, vec![10, 20, 30], vec!["X", "Y"]);
+
+    let s = df.get_as::<f64>(&"Y").unwrap();
+    assert_eq!(s.isnull().values.as_ref(), &vec![false, true, false]);
+    assert_eq!(s.mean(), 2.5);
+    assert_eq!(s.fillna(FillNA::Ffill),
+               Series::<f64, i64>::new(vec![1.5, 1.5, 3.5], vec![10, 20, 30]));
+
+    let s = df.get(&"Y").as_series::<f64>().unwrap();
+    assert_eq!(s.count(), 2);
+
+    let s = df.get_as::<i64>(&"X").unwrap();
+    assert!(!s.has_null());
+}
+
+#[test]
+fn test_series_rolling_shift_with_null() {
+    let s = Series::<i64, usize>::with_mask(vec![1, 2, 3], vec![0, 1, 2], vec![false, true, false]);
+
+    let exp = NullVec::with_mask(vec![1, 0, 4], Some(vec![false, true, false]));
+    assert_eq!(s.cumsum().values.as_ref(), &Array::Int64Array(exp));
+
+    let exp = NullVec::with_mask(vec![0, 1, 0], Some(vec![true, false, true]));
+    assert_eq!(s.shift(1).values.as_ref(), &Array::Int64Array(exp));
+}
+
+#[test]
+fn test_series_format_null() {
+    let s = Series::<i64, &str>::with_mask(vec![1, 2], vec!["a", "b"], vec![false, true]);
+    assert_eq!(format!("{:?}", s), "a    1\nb Null");
+}
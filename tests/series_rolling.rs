@@ -0,0 +1,95 @@
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+#[test]
+fn test_series_rolling_sum_mean() {
+    let s = Series::<i64, &str>::new(vec![1, 2, 3, 4, 5], vec!["a", "b", "c", "d", "e"]);
+    let r = s.rolling(3, 2, false);
+
+    let exp = Column::new(Array::Float64Array(NullVec::with_mask(vec![0., 3., 6., 9., 12.],
+                                                                 Some(vec![true, false, false,
+                                                                           false, false]))),
+                          vec!["a", "b", "c", "d", "e"]);
+    assert_eq!(r.sum(), exp);
+
+    let exp = Column::new(Array::Float64Array(NullVec::with_mask(vec![0., 1.5, 2., 3., 4.],
+                                                                 Some(vec![true, false, false,
+                                                                           false, false]))),
+                          vec!["a", "b", "c", "d", "e"]);
+    assert_eq!(r.mean(), exp);
+    let exp = NullVec::with_mask(vec![0., 2., 3., 3., 3.],
+                                 Some(vec![true, false, false, false, false]));
+    assert_eq!(r.count().values.as_ref(), &Array::Float64Array(exp));
+}
+
+#[test]
+fn test_series_rolling_center_min_max() {
+    let s = Series::<f64, usize>::from_vec(vec![3., 1., 4., 1., 5., 9.]);
+    let r = s.rolling(3, 3, true);
+
+    let exp = NullVec::with_mask(vec![0., 1., 1., 1., 1., 0.],
+                                 Some(vec![true, false, false, false, false, true]));
+    assert_eq!(r.min().values.as_ref(), &Array::Float64Array(exp));
+    let exp = NullVec::with_mask(vec![0., 4., 4., 5., 9., 0.],
+                                 Some(vec![true, false, false, false, false, true]));
+    assert_eq!(r.max().values.as_ref(), &Array::Float64Array(exp));
+}
+
+#[test]
+fn test_series_rolling_std_apply() {
+    let s = Series::<f64, usize>::from_vec(vec![1., 3., 5., 7.]);
+    let r = s.rolling(2, 2, false);
+
+    let exp = NullVec::with_mask(vec![0., 2., 2., 2.], Some(vec![true, false, false, false]));
+    assert_eq!(r.unbiased_var().values.as_ref(), &Array::Float64Array(exp));
+    let exp = NullVec::with_mask(vec![0., 1., 1., 1.], Some(vec![true, false, false, false]));
+    assert_eq!(r.std().values.as_ref(), &Array::Float64Array(exp));
+
+    let res = r.apply(&|x: &Vec<f64>| x[0] * x[1]);
+    let exp = NullVec::with_mask(vec![0., 3., 15., 35.], Some(vec![true, false, false, false]));
+    assert_eq!(res.values.as_ref(), &Array::Float64Array(exp));
+}
+
+#[test]
+fn test_series_expanding() {
+    let s = Series::<f64, usize>::from_vec(vec![1., 3., 5., 7.]);
+    let r = s.expanding(2);
+
+    let exp = NullVec::with_mask(vec![0., 2., 3., 4.], Some(vec![true, false, false, false]));
+    assert_eq!(r.mean().values.as_ref(), &Array::Float64Array(exp));
+    let exp = NullVec::with_mask(vec![0., 1., 1., 1.], Some(vec![true, false, false, false]));
+    assert_eq!(r.min().values.as_ref(), &Array::Float64Array(exp));
+    let exp = NullVec::with_mask(vec![0., 3., 5., 7.], Some(vec![true, false, false, false]));
+    assert_eq!(r.max().values.as_ref(), &Array::Float64Array(exp));
+    let exp = NullVec::with_mask(vec![0., 1., 8. / 3., 5.], Some(vec![true, false, false, false]));
+    assert_eq!(r.var().values.as_ref(), &Array::Float64Array(exp));
+}
+
+#[test]
+fn test_series_ewm() {
+    let s = Series::<i64, &str>::new(vec![1, 2, 4], vec!["a", "b", "c"]);
+
+    let r = s.ewm(Decay::Span(3.), false);
+    let exp = Column::new(Array::new(vec![1., 1.5, 2.75]), vec!["a", "b", "c"]);
+    assert_eq!(r.mean(), exp);
+    let exp = Column::new(Array::new(vec![0., 0.25, 1.6875]), vec!["a", "b", "c"]);
+    assert_eq!(r.var(), exp);
+
+    // bias-corrected values are null until two observations exist
+    let res = match *r.unbiased_var().values {
+        Array::Float64Array(ref v) => v.clone(),
+        _ => panic!("unexpected dtype"),
+    };
+    assert_eq!(res.is_null(), vec![true, false, false]);
+    let res: Vec<f64> = res.iter_not_null().cloned().collect();
+    assert!((res[0] - 0.5).abs() < 1e-10);
+    assert!((res[1] - 2.7).abs() < 1e-10);
+
+    let r = s.ewm(Decay::Alpha(0.5), true);
+    let res: Vec<f64> = match *r.mean().values {
+        Array::Float64Array(ref v) => v.iter_not_null().cloned().collect(),
+        _ => panic!("unexpected dtype"),
+    };
+    assert!((res[1] - 5. / 3.).abs() < 1e-10);
+    assert!((res[2] - 3.).abs() < 1e-10);
+}
@@ -0,0 +1,69 @@
+#[macro_use]
+extern crate brassfibre;
+use brassfibre::prelude::*;
+
+#[test]
+fn test_series_cumulative() {
+    let s = Series::<f64, &str>::new(vec![2., f64::NAN, 1., 3.], vec!["a", "b", "c", "d"]);
+    let mask = Some(vec![false, true, false, false]);
+
+    let exp = Column::new(Array::Float64Array(NullVec::with_mask(vec![2., 0., 3., 6.], mask.clone())),
+                          vec!["a", "b", "c", "d"]);
+    assert_eq!(s.cumsum(), exp);
+    let exp = NullVec::with_mask(vec![2., 0., 2., 6.], mask.clone());
+    assert_eq!(s.cumprod().values.as_ref(), &Array::Float64Array(exp));
+    let exp = NullVec::with_mask(vec![2., 0., 2., 3.], mask.clone());
+    assert_eq!(s.cummax().values.as_ref(), &Array::Float64Array(exp));
+    let exp = NullVec::with_mask(vec![2., 0., 1., 1.], mask.clone());
+    assert_eq!(s.cummin().values.as_ref(), &Array::Float64Array(exp));
+}
+
+#[test]
+fn test_series_cumulative_int() {
+    let s = Series::<i64, &str>::new(vec![9007199254740992, 1, -2], vec!["a", "b", "c"]);
+
+    // integer dtype is kept
+    let exp = Column::new(array![9007199254740992i64, 9007199254740993, 9007199254740991],
+                          vec!["a", "b", "c"]);
+    assert_eq!(s.cumsum(), exp);
+    let exp = array![9007199254740992i64, 9007199254740992, 9007199254740992];
+    assert_eq!(s.cummax().values.as_ref(), &exp);
+
+    let s = Series::<u8, usize>::with_mask(vec![3, 0, 2], vec![0, 1, 2], vec![false, true, false]);
+    let exp = NullVec::with_mask(vec![3u8, 0, 6], Some(vec![false, true, false]));
+    assert_eq!(s.cumprod().values.as_ref(), &Array::UInt8Array(exp));
+}
+
+#[test]
+fn test_series_shift() {
+    let s = Series::<i64, &str>::new(vec![1, 2, 4, 8], vec!["a", "b", "c", "d"]);
+
+    let exp = Column::new(Array::Int64Array(NullVec::with_mask(vec![0, 1, 2, 4],
+                                                               Some(vec![true, false, false,
+                                                                         false]))),
+                          vec!["a", "b", "c", "d"]);
+    assert_eq!(s.shift(1), exp);
+    let exp = NullVec::with_mask(vec![4, 8, 0, 0], Some(vec![false, false, true, true]));
+    assert_eq!(s.shift(-2).values.as_ref(), &Array::Int64Array(exp));
+    assert_eq!(s.shift(0).values.as_ref(), &Array::new(vec![1i64, 2, 4, 8]));
+
+    let s = Series::<String, usize>::from_vec(vec!["a".to_string(), "b".to_string()]);
+    let exp = NullVec::with_mask(vec!["".to_string(), "a".to_string()], Some(vec![true, false]));
+    assert_eq!(s.shift(1).values.as_ref(), &Array::StringArray(exp));
+}
+
+#[test]
+fn test_series_diff_pct_change() {
+    let s = Series::<i64, &str>::new(vec![1, 2, 4, 8], vec!["a", "b", "c", "d"]);
+
+    let exp = Column::new(Array::Float64Array(NullVec::with_mask(vec![0., 1., 2., 4.],
+                                                                 Some(vec![true, false, false,
+                                                                           false]))),
+                          vec!["a", "b", "c", "d"]);
+    assert_eq!(s.diff(1), exp);
+    let exp = NullVec::with_mask(vec![-3., -6., 0., 0.], Some(vec![false, false, true, true]));
+    assert_eq!(s.diff(-2).values.as_ref(), &Array::Float64Array(exp));
+
+    let exp = NullVec::with_mask(vec![0., 1., 1., 1.], Some(vec![true, false, false, false]));
+    assert_eq!(s.pct_change(1).values.as_ref(), &Array::Float64Array(exp));
+}